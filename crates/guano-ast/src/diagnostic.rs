@@ -0,0 +1,512 @@
+use std::fmt::{self, Write};
+
+use guano_common::{
+    rowan::TextRange,
+    source_map::{FileId, FileSpan, SourceMap},
+};
+
+use crate::parsing::{
+    combinators::errors::{CombinatorError, ExpectError},
+    error::{Error, ErrorKind},
+    lexer::{LexError, LexErrorKind},
+};
+
+/// How serious a [Diagnostic] is.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Severity {
+    Help,
+    Note,
+    Warning,
+    Error,
+}
+
+impl Severity {
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Severity::Help => "help",
+            Severity::Note => "note",
+            Severity::Warning => "warning",
+            Severity::Error => "error",
+        }
+    }
+}
+
+impl fmt::Display for Severity {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// A stable identifier for a kind of diagnostic, such as `E0001`.
+///
+/// Codes never change meaning once they are published,
+/// so they can be searched for and documented.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Code(&'static str);
+
+impl Code {
+    /// A required piece of syntax, like `';'` or `identifier`, is missing.
+    pub const EXPECTED: Code = Code("E0001");
+    /// A required piece of syntax is missing, described by the parser.
+    pub const MISSING: Code = Code("E0002");
+    /// Input was skipped to recover from a syntax error.
+    pub const SKIPPED: Code = Code("E0003");
+    /// Syntax that is not allowed at this position.
+    pub const UNEXPECTED: Code = Code("E0004");
+    /// The parser itself misbehaved.
+    pub const INTERNAL: Code = Code("E0005");
+    /// A string, char or block comment is never closed.
+    pub const UNTERMINATED: Code = Code("E0006");
+
+    #[inline]
+    pub const fn new(code: &'static str) -> Self {
+        Self(code)
+    }
+
+    #[inline]
+    pub const fn as_str(self) -> &'static str {
+        self.0
+    }
+}
+
+impl fmt::Display for Code {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.0)
+    }
+}
+
+/// A span of source code that a [Diagnostic] points at.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Label {
+    pub span: FileSpan,
+    pub message: Option<String>,
+    /// Primary labels are underlined with `^`,
+    /// secondary labels with `-`.
+    pub primary: bool,
+}
+
+impl Label {
+    #[inline]
+    pub fn primary(span: FileSpan) -> Self {
+        Self {
+            span,
+            message: None,
+            primary: true,
+        }
+    }
+
+    #[inline]
+    pub fn secondary(span: FileSpan) -> Self {
+        Self {
+            span,
+            message: None,
+            primary: false,
+        }
+    }
+
+    #[inline]
+    pub fn with_message(mut self, message: impl Into<String>) -> Self {
+        self.message = Some(message.into());
+        self
+    }
+}
+
+/// A message about the source code, reported by any phase of the compiler.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Diagnostic {
+    pub severity: Severity,
+    pub code: Option<Code>,
+    pub message: String,
+    pub labels: Vec<Label>,
+    pub notes: Vec<String>,
+    pub help: Vec<String>,
+}
+
+impl Diagnostic {
+    #[inline]
+    pub fn new(severity: Severity, message: impl Into<String>) -> Self {
+        Self {
+            severity,
+            code: None,
+            message: message.into(),
+            labels: vec![],
+            notes: vec![],
+            help: vec![],
+        }
+    }
+
+    #[inline]
+    pub fn error(message: impl Into<String>) -> Self {
+        Self::new(Severity::Error, message)
+    }
+
+    #[inline]
+    pub fn warning(message: impl Into<String>) -> Self {
+        Self::new(Severity::Warning, message)
+    }
+
+    #[inline]
+    pub fn with_code(mut self, code: Code) -> Self {
+        self.code = Some(code);
+        self
+    }
+
+    #[inline]
+    pub fn with_label(mut self, label: Label) -> Self {
+        self.labels.push(label);
+        self
+    }
+
+    #[inline]
+    pub fn with_note(mut self, note: impl Into<String>) -> Self {
+        self.notes.push(note.into());
+        self
+    }
+
+    #[inline]
+    pub fn with_help(mut self, help: impl Into<String>) -> Self {
+        self.help.push(help.into());
+        self
+    }
+
+    /// The span of the first primary label, if there is one.
+    pub fn primary_span(&self) -> Option<FileSpan> {
+        self.labels.iter().find(|l| l.primary).map(|l| l.span)
+    }
+
+    /// Describe an error from parsing a file.
+    pub fn from_error(file: FileId, error: &Error<'_>) -> Self {
+        let mut label = error
+            .span
+            .map(|span| Label::primary(FileSpan::new(file, span)));
+
+        let diagnostic = match &error.kind {
+            ErrorKind::Combinator(CombinatorError::Expect(ExpectError::Error(inner))) => {
+                // The outer span covers the text that was replaced
+                // by an `ERROR` token, so it replaces the inner labels.
+                let mut diagnostic = Diagnostic::from_error(file, inner);
+                diagnostic.labels.clear();
+                diagnostic
+            }
+            ErrorKind::Combinator(CombinatorError::Expect(ExpectError::Str(message))) => {
+                Diagnostic::error(message.as_ref()).with_code(Code::MISSING)
+            }
+            ErrorKind::Combinator(
+                CombinatorError::Expected(_)
+                | CombinatorError::Tag(_)
+                | CombinatorError::Char(_)
+                | CombinatorError::Token(_)
+                | CombinatorError::Regex(_),
+            ) => Diagnostic::error(error.kind.to_string()).with_code(Code::EXPECTED),
+            ErrorKind::Skipped(message) => {
+                label = label.map(|l| l.with_message("this input was skipped"));
+                Diagnostic::error(message.as_ref()).with_code(Code::SKIPPED)
+            }
+            ErrorKind::Combinator(CombinatorError::Not(_)) | ErrorKind::String(_) => {
+                Diagnostic::error(error.kind.to_string()).with_code(Code::UNEXPECTED)
+            }
+            ErrorKind::Combinator(CombinatorError::Ast)
+            | ErrorKind::InvalidPosition(_)
+            | ErrorKind::NeedBytes(_)
+            | ErrorKind::NeedChars(_) => Diagnostic::error(error.kind.to_string())
+                .with_code(Code::INTERNAL)
+                .with_note("this is a bug in the parser"),
+        };
+
+        match label {
+            Some(label) => diagnostic.with_label(label),
+            None => diagnostic,
+        }
+    }
+
+    /// Describe an error from lexing a file.
+    pub fn from_lex_error(file: FileId, error: &LexError) -> Self {
+        let closing = match error.kind {
+            LexErrorKind::UnterminatedString => "\"",
+            LexErrorKind::UnterminatedChar => "'",
+            LexErrorKind::UnterminatedComment => "*/",
+        };
+
+        Diagnostic::error(error.kind.to_string())
+            .with_code(Code::UNTERMINATED)
+            .with_label(
+                Label::primary(FileSpan::new(file, error.range)).with_message("starts here"),
+            )
+            .with_help(format!("add a closing `{closing}`"))
+    }
+}
+
+/// Renders [Diagnostic]s as caret-underlined snippets of the files they point at.
+///
+/// ```text
+/// error[E0001]: Expected ';'
+///  --> main.guano:1:10
+///   |
+/// 1 | let x = 5
+///   |          ^
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct Renderer<'map> {
+    map: &'map SourceMap,
+}
+
+impl<'map> Renderer<'map> {
+    #[inline]
+    pub fn new(map: &'map SourceMap) -> Self {
+        Self { map }
+    }
+
+    /// The one-based line and character column of the start of a span.
+    pub fn line_col(&self, span: FileSpan) -> (usize, usize) {
+        let file = &self.map[span.file];
+        let offset = file.clamp(span.start());
+        let line = file.line_index(offset);
+        let start = file.line_range(line).unwrap().start();
+        let column = file.text()[TextRange::new(start, offset)].chars().count();
+
+        (line + 1, column + 1)
+    }
+
+    /// Render a diagnostic into a string.
+    pub fn render(&self, diagnostic: &Diagnostic) -> String {
+        let mut out = String::new();
+        self.write(&mut out, diagnostic)
+            .expect("Writing to a string can't fail");
+
+        out
+    }
+
+    /// Render a diagnostic into a writer.
+    ///
+    /// Labels are grouped by file, starting with the file of the primary label.
+    pub fn write(&self, out: &mut impl Write, diagnostic: &Diagnostic) -> fmt::Result {
+        write!(out, "{}", diagnostic.severity)?;
+        if let Some(code) = diagnostic.code {
+            write!(out, "[{code}]")?;
+        }
+        writeln!(out, ": {}", diagnostic.message)?;
+
+        let mut labels = diagnostic
+            .labels
+            .iter()
+            .map(|label| {
+                let file = &self.map[label.span.file];
+                let start = file.clamp(label.span.start());
+                let end = file.clamp(label.span.end()).max(start);
+
+                (
+                    label.span.file,
+                    file.line_index(start),
+                    TextRange::new(start, end),
+                    label,
+                )
+            })
+            .collect::<Vec<_>>();
+
+        let first_file = diagnostic
+            .primary_span()
+            .or_else(|| diagnostic.labels.first().map(|l| l.span))
+            .map(|span| span.file);
+        labels.sort_by_key(|(file, line, span, label)| {
+            (
+                Some(*file) != first_file,
+                *file,
+                *line,
+                span.start(),
+                !label.primary,
+            )
+        });
+
+        let gutter = labels
+            .iter()
+            .map(|(_, line, ..)| (line + 1).to_string().len())
+            .max()
+            .unwrap_or(0);
+        let pad = " ".repeat(gutter);
+
+        let mut previous = None;
+        for (file_id, line, span, label) in &labels {
+            let file = &self.map[*file_id];
+
+            match previous {
+                Some((previous_file, _)) if previous_file == *file_id => {}
+                _ => {
+                    // The primary label points at the diagnostic's location.
+                    let location = diagnostic
+                        .labels
+                        .iter()
+                        .filter(|l| l.span.file == *file_id)
+                        .min_by_key(|l| !l.primary)
+                        .map_or(*span, |l| l.span.range);
+                    let arrow = if previous.is_none() { "-->" } else { ":::" };
+                    let (line, column) = self.line_col(FileSpan::new(*file_id, location));
+
+                    writeln!(out, "{pad}{arrow} {}:{line}:{column}", file.name())?;
+                    writeln!(out, "{pad} |")?;
+                    previous = None;
+                }
+            }
+
+            let line_range = file.line_range(*line).unwrap();
+            let text = &file.text()[line_range];
+
+            if previous.map(|(_, line)| line) != Some(*line) {
+                if matches!(previous, Some((_, prev)) if prev + 1 < *line) {
+                    writeln!(out, "...")?;
+                }
+
+                writeln!(out, "{:>gutter$} | {text}", line + 1)?;
+            }
+            previous = Some((*file_id, *line));
+
+            // Spans over multiple lines are only underlined on their first line.
+            let end = span.end().min(line_range.end());
+            let prefix = &file.text()[TextRange::new(line_range.start(), span.start())];
+            let underlined = &file.text()[TextRange::new(span.start(), end.max(span.start()))];
+
+            let indent = prefix
+                .chars()
+                .map(|c| if c == '\t' { '\t' } else { ' ' })
+                .collect::<String>();
+            let marker = if label.primary { "^" } else { "-" };
+            let underline = marker.repeat(underlined.chars().count().max(1));
+
+            write!(out, "{pad} | {indent}{underline}")?;
+            if let Some(message) = &label.message {
+                write!(out, " {message}")?;
+            }
+            writeln!(out)?;
+        }
+
+        if !diagnostic.notes.is_empty() || !diagnostic.help.is_empty() {
+            if labels.is_empty() {
+                writeln!(out, "{pad} |")?;
+            }
+
+            for note in &diagnostic.notes {
+                writeln!(out, "{pad} = note: {note}")?;
+            }
+
+            for help in &diagnostic.help {
+                writeln!(out, "{pad} = help: {help}")?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use guano_common::{
+        rowan::{TextRange, TextSize},
+        source_map::{FileId, FileSpan, SourceMap},
+    };
+
+    use super::{Code, Diagnostic, Label, Renderer};
+
+    fn span(file: FileId, start: u32, end: u32) -> FileSpan {
+        FileSpan::new(
+            file,
+            TextRange::new(TextSize::from(start), TextSize::from(end)),
+        )
+    }
+
+    #[test]
+    fn test_render() {
+        let mut map = SourceMap::new();
+        let file = map.add("main.guano", "fun main() {\n    let x = 5\n    x + y\n}\n");
+        let renderer = Renderer::new(&map);
+
+        let diagnostic = Diagnostic::error("Unknown variable `y`")
+            .with_code(Code::new("E1000"))
+            .with_label(Label::primary(span(file, 35, 36)).with_message("not found in this scope"))
+            .with_label(
+                Label::secondary(span(file, 17, 26)).with_message("similar variable declared here"),
+            )
+            .with_note("variables must be declared before use")
+            .with_help("did you mean `x`?");
+
+        let expected = "\
+error[E1000]: Unknown variable `y`
+ --> main.guano:3:9
+  |
+2 |     let x = 5
+  |     --------- similar variable declared here
+3 |     x + y
+  |         ^ not found in this scope
+  = note: variables must be declared before use
+  = help: did you mean `x`?
+";
+
+        assert_eq!(renderer.render(&diagnostic), expected);
+    }
+
+    #[test]
+    fn test_render_files() {
+        let mut map = SourceMap::new();
+        let lib = map.add("lib.guano", "fun add(a: int, b: int): int {}\n");
+        let main = map.add("main.guano", "import lib;\n\nlet x = lib.add(1);\n");
+        let renderer = Renderer::new(&map);
+
+        let diagnostic = Diagnostic::error("Expected 2 arguments, found 1")
+            .with_label(Label::secondary(span(lib, 4, 7)).with_message("defined here"))
+            .with_label(Label::primary(span(main, 21, 31)));
+
+        let expected = "\
+error: Expected 2 arguments, found 1
+ --> main.guano:3:9
+  |
+3 | let x = lib.add(1);
+  |         ^^^^^^^^^^
+ ::: lib.guano:1:5
+  |
+1 | fun add(a: int, b: int): int {}
+  |     --- defined here
+";
+
+        assert_eq!(renderer.render(&diagnostic), expected);
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let mut map = SourceMap::new();
+        let file = map.add("main.guano", "fun main() {\n\tlet x = 5 @@@;\n}\n");
+        let (context, _) = crate::parse_file(map[file].text());
+        let renderer = Renderer::new(&map);
+
+        let diagnostics = context
+            .errors()
+            .iter()
+            .map(|e| Diagnostic::from_error(file, e))
+            .collect::<Vec<_>>();
+
+        let rendered = diagnostics
+            .iter()
+            .map(|d| renderer.render(d))
+            .collect::<Vec<_>>();
+
+        // Tabs are kept in the underline, so the carets line up with the source.
+        let expected = [
+            "\
+error[E0001]: Expected ';'
+ --> main.guano:2:12
+  |
+2 | \tlet x = 5 @@@;
+  | \t          ^
+",
+            "\
+error[E0003]: Expected statement or expression
+ --> main.guano:2:12
+  |
+2 | \tlet x = 5 @@@;
+  | \t          ^^^^ this input was skipped
+",
+        ];
+
+        assert_eq!(rendered, expected);
+        assert_eq!(diagnostics[1].primary_span(), Some(span(file, 24, 28)));
+
+        assert_eq!(renderer.line_col(span(file, 15, 15)), (2, 3));
+    }
+}
@@ -0,0 +1,1181 @@
+use std::{fmt::Write, fs, io, path::Path as FsPath};
+
+use guano_common::{
+    ahash::{AHashMap, AHashSet},
+    rowan::{ast::AstNode, NodeOrToken, WalkEvent},
+    source_map::SourceMap,
+};
+use guano_syntax::{
+    nodes::{
+        Class, ClassField, Decl, Enum, EnumVariant, Func, Impl, ModuleItem, Path, PathSegment,
+        Proto, SourceFile, Type, Var,
+    },
+    AstToken, HasDocs, SyntaxKind, SyntaxNode, SyntaxToken,
+};
+
+use crate::parsing::display::needs_space;
+
+/// The kinds of items that get documented.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum ItemKind {
+    Class,
+    Proto,
+    Enum,
+    Func,
+    Var,
+}
+
+impl ItemKind {
+    pub const ALL: [ItemKind; 5] = [
+        ItemKind::Class,
+        ItemKind::Proto,
+        ItemKind::Enum,
+        ItemKind::Func,
+        ItemKind::Var,
+    ];
+
+    /// The prefix of the anchors of items of this kind.
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            ItemKind::Class => "class",
+            ItemKind::Proto => "proto",
+            ItemKind::Enum => "enum",
+            ItemKind::Func => "fun",
+            ItemKind::Var => "var",
+        }
+    }
+
+    const fn heading(self) -> &'static str {
+        match self {
+            ItemKind::Class => "Classes",
+            ItemKind::Proto => "Protos",
+            ItemKind::Enum => "Enums",
+            ItemKind::Func => "Functions",
+            ItemKind::Var => "Variables",
+        }
+    }
+}
+
+/// Where an item is documented.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Link {
+    /// The index of the module in [Documentation::modules].
+    pub module: usize,
+    pub anchor: String,
+}
+
+/// Part of a signature, types that are documented link to their item.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Segment {
+    Text(String),
+    Link { text: String, link: Link },
+}
+
+pub type Signature = Vec<Segment>;
+
+/// A class field or an enum variant.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FieldDoc {
+    pub name: String,
+    pub is_pub: bool,
+    pub signature: Signature,
+    pub docs: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ItemDoc {
+    pub kind: ItemKind,
+    pub name: String,
+    pub anchor: String,
+    pub is_pub: bool,
+    pub signature: Signature,
+    pub docs: Option<String>,
+    /// The fields of a class, or the variants of an enum.
+    pub fields: Vec<FieldDoc>,
+    /// The functions a proto requires, which don't have a body.
+    pub requirements: Vec<ItemDoc>,
+    /// The functions of a class from `impl`s, or those a proto provides.
+    pub methods: Vec<ItemDoc>,
+    /// The protos a class implements with `impl Proto on Class`.
+    pub implements: Vec<Signature>,
+}
+
+impl ItemDoc {
+    fn new(
+        kind: ItemKind,
+        name: String,
+        anchor: String,
+        is_pub: bool,
+        signature: Signature,
+    ) -> ItemDoc {
+        ItemDoc {
+            kind,
+            name,
+            anchor,
+            is_pub,
+            signature,
+            docs: None,
+            fields: vec![],
+            requirements: vec![],
+            methods: vec![],
+            implements: vec![],
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ModuleDoc {
+    /// The names of the file's directories and the file itself,
+    /// followed by the names of any nested modules.
+    pub path: Vec<String>,
+    pub is_pub: bool,
+    pub docs: Option<String>,
+    pub items: Vec<ItemDoc>,
+}
+
+impl ModuleDoc {
+    #[inline]
+    pub fn name(&self) -> String {
+        self.path.join("::")
+    }
+}
+
+/// The format of the generated pages.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    Html,
+    Markdown,
+}
+
+impl Format {
+    pub const fn extension(self) -> &'static str {
+        match self {
+            Format::Html => "html",
+            Format::Markdown => "md",
+        }
+    }
+}
+
+/// A generated file, relative to the output directory.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Page {
+    pub path: String,
+    pub content: String,
+}
+
+/// The documentation of a set of files, with a page per module.
+#[derive(Debug, Clone, Default)]
+pub struct Documentation {
+    modules: Vec<ModuleDoc>,
+    /// The file name of each module's page, without an extension.
+    pages: Vec<String>,
+}
+
+impl Documentation {
+    /// Document files, each of which is a module named after it.
+    /// A name like `a::b` puts the file's module inside of `a`.
+    pub fn new(files: impl IntoIterator<Item = (String, SourceFile)>) -> Self {
+        let mut builder = Builder::default();
+
+        for (name, file) in files {
+            let path = name.split("::").map(str::to_string).collect();
+            builder.collect(path, true, file.docs(), file.items().collect());
+        }
+
+        builder.build()
+    }
+
+    /// Parse and document every file in a [SourceMap], naming each one after
+    /// its path from the directory that all of the files are in. Files with
+    /// syntax errors are documented as far as the parser could recover.
+    pub fn from_source_map(map: &SourceMap) -> Self {
+        let paths = map
+            .files()
+            .map(|(_, file)| file.name().split(['/', '\\']).collect::<Vec<_>>())
+            .collect::<Vec<_>>();
+
+        // The number of directories that every file is in.
+        let shared = match paths.split_first() {
+            Some((first, rest)) => rest.iter().fold(first.len() - 1, |shared, path| {
+                path[..path.len() - 1]
+                    .iter()
+                    .zip(&first[..shared])
+                    .take_while(|(a, b)| a == b)
+                    .count()
+            }),
+            None => 0,
+        };
+
+        Self::new(map.files().zip(&paths).map(|((_, file), path)| {
+            let (file_name, dirs) = path.split_last().unwrap();
+            let stem = match file_name.rsplit_once('.') {
+                Some((stem, _)) if !stem.is_empty() => stem,
+                _ => file_name,
+            };
+
+            let mut name = dirs[shared..].to_vec();
+            name.push(stem);
+
+            (name.join("::"), crate::parse_file(file.text()).1)
+        }))
+    }
+
+    #[inline]
+    pub fn modules(&self) -> &[ModuleDoc] {
+        &self.modules
+    }
+
+    /// The file name of a module's page.
+    pub fn page_name(&self, module: usize, format: Format) -> String {
+        format!("{}.{}", self.pages[module], format.extension())
+    }
+
+    /// An index page, followed by a page for every module.
+    pub fn pages(&self, format: Format) -> Vec<Page> {
+        let index = Page {
+            path: format!("index.{}", format.extension()),
+            content: self.index(format),
+        };
+
+        let modules = (0..self.modules.len()).map(|module| Page {
+            path: self.page_name(module, format),
+            content: self.module_page(module, format),
+        });
+
+        std::iter::once(index).chain(modules).collect()
+    }
+
+    /// Write every page into `dir`, creating it if it doesn't exist.
+    pub fn write(&self, dir: &FsPath, format: Format) -> io::Result<()> {
+        fs::create_dir_all(dir)?;
+
+        for page in self.pages(format) {
+            fs::write(dir.join(page.path), page.content)?;
+        }
+
+        Ok(())
+    }
+
+    fn index(&self, format: Format) -> String {
+        let mut out = String::new();
+        let modules = self.modules.iter().enumerate().map(|(i, module)| {
+            let summary = module.docs.as_deref().and_then(|d| d.lines().next());
+            (self.page_name(i, format), module.name(), summary)
+        });
+
+        match format {
+            Format::Html => {
+                out.push_str(&html_header("Modules"));
+                out.push_str("<h1>Modules</h1>\n<ul>\n");
+                for (page, name, summary) in modules {
+                    write!(
+                        out,
+                        "<li><a href=\"{page}\"><code>{}</code></a>",
+                        escape(&name)
+                    )
+                    .unwrap();
+                    if let Some(summary) = summary {
+                        write!(out, " — {}", escape(summary)).unwrap();
+                    }
+                    out.push_str("</li>\n");
+                }
+                out.push_str("</ul>\n</body>\n</html>\n");
+            }
+            Format::Markdown => {
+                out.push_str("# Modules\n\n");
+                for (page, name, summary) in modules {
+                    write!(out, "- [`{name}`]({page})").unwrap();
+                    if let Some(summary) = summary {
+                        write!(out, " — {summary}").unwrap();
+                    }
+                    out.push('\n');
+                }
+            }
+        }
+
+        out
+    }
+
+    fn module_page(&self, index: usize, format: Format) -> String {
+        let module = &self.modules[index];
+        let name = module.name();
+        let submodules = self
+            .modules
+            .iter()
+            .enumerate()
+            .filter(|(_, m)| {
+                m.path.len() == module.path.len() + 1 && m.path.starts_with(&module.path)
+            })
+            .map(|(i, m)| (self.page_name(i, format), m))
+            .collect::<Vec<_>>();
+
+        let mut out = String::new();
+
+        match format {
+            Format::Html => {
+                out.push_str(&html_header(&name));
+                writeln!(
+                    out,
+                    "<nav><a href=\"index.html\">Index</a></nav>\n<h1>Module <code>{}</code></h1>",
+                    escape(&name)
+                )
+                .unwrap();
+                out.push_str(&html_docs(module.docs.as_deref()));
+
+                if !submodules.is_empty() {
+                    out.push_str("<h2>Modules</h2>\n<ul>\n");
+                    for (page, m) in &submodules {
+                        let name = m.path.last().unwrap();
+                        writeln!(
+                            out,
+                            "<li><a href=\"{page}\"><code>{}</code></a>{}</li>",
+                            escape(name),
+                            private_html(m.is_pub)
+                        )
+                        .unwrap();
+                    }
+                    out.push_str("</ul>\n");
+                }
+
+                for kind in ItemKind::ALL {
+                    let items = module
+                        .items
+                        .iter()
+                        .filter(|i| i.kind == kind)
+                        .collect::<Vec<_>>();
+                    if items.is_empty() {
+                        continue;
+                    }
+
+                    writeln!(out, "<h2>{}</h2>", kind.heading()).unwrap();
+                    for item in items {
+                        self.html_item(&mut out, item, 3);
+                    }
+                }
+
+                out.push_str("</body>\n</html>\n");
+            }
+            Format::Markdown => {
+                write!(out, "[Index](index.md)\n\n# Module `{name}`\n\n").unwrap();
+                out.push_str(&markdown_docs(module.docs.as_deref()));
+
+                if !submodules.is_empty() {
+                    out.push_str("## Modules\n\n");
+                    for (page, m) in &submodules {
+                        let name = m.path.last().unwrap();
+                        writeln!(out, "- [`{name}`]({page}){}", private_markdown(m.is_pub))
+                            .unwrap();
+                    }
+                    out.push('\n');
+                }
+
+                for kind in ItemKind::ALL {
+                    let items = module
+                        .items
+                        .iter()
+                        .filter(|i| i.kind == kind)
+                        .collect::<Vec<_>>();
+                    if items.is_empty() {
+                        continue;
+                    }
+
+                    write!(out, "## {}\n\n", kind.heading()).unwrap();
+                    for item in items {
+                        self.markdown_item(&mut out, item, 3);
+                    }
+                }
+            }
+        }
+
+        out
+    }
+
+    fn html_item(&self, out: &mut String, item: &ItemDoc, level: usize) {
+        writeln!(
+            out,
+            "<section id=\"{}\">\n<h{level}>{} <code>{}</code>{}</h{level}>\n<pre><code>{}</code></pre>",
+            item.anchor,
+            item.kind.as_str(),
+            escape(&item.name),
+            private_html(item.is_pub),
+            self.html_signature(&item.signature, Format::Html),
+        )
+        .unwrap();
+        out.push_str(&html_docs(item.docs.as_deref()));
+
+        if !item.fields.is_empty() {
+            let heading = match item.kind {
+                ItemKind::Enum => "Variants",
+                _ => "Fields",
+            };
+
+            writeln!(out, "<h{0}>{heading}</h{0}>\n<ul>", level + 1).unwrap();
+            for field in &item.fields {
+                writeln!(
+                    out,
+                    "<li><code>{}</code>{}{}</li>",
+                    self.html_signature(&field.signature, Format::Html),
+                    private_html(field.is_pub),
+                    html_docs(field.docs.as_deref()),
+                )
+                .unwrap();
+            }
+            out.push_str("</ul>\n");
+        }
+
+        if !item.implements.is_empty() {
+            writeln!(out, "<h{0}>Implements</h{0}>\n<ul>", level + 1).unwrap();
+            for proto in &item.implements {
+                writeln!(
+                    out,
+                    "<li><code>{}</code></li>",
+                    self.html_signature(proto, Format::Html)
+                )
+                .unwrap();
+            }
+            out.push_str("</ul>\n");
+        }
+
+        for (heading, funcs) in [
+            ("Required functions", &item.requirements),
+            ("Functions", &item.methods),
+        ] {
+            if funcs.is_empty() {
+                continue;
+            }
+
+            writeln!(out, "<h{0}>{heading}</h{0}>", level + 1).unwrap();
+            for func in funcs {
+                self.html_item(out, func, level + 2);
+            }
+        }
+
+        out.push_str("</section>\n");
+    }
+
+    fn markdown_item(&self, out: &mut String, item: &ItemDoc, level: usize) {
+        write!(
+            out,
+            "<a id=\"{}\"></a>\n\n{} {} `{}`{}\n\n<pre><code>{}</code></pre>\n\n",
+            item.anchor,
+            "#".repeat(level),
+            item.kind.as_str(),
+            item.name,
+            private_markdown(item.is_pub),
+            self.html_signature(&item.signature, Format::Markdown),
+        )
+        .unwrap();
+        out.push_str(&markdown_docs(item.docs.as_deref()));
+
+        if !item.fields.is_empty() {
+            let heading = match item.kind {
+                ItemKind::Enum => "Variants",
+                _ => "Fields",
+            };
+
+            write!(out, "{} {heading}\n\n", "#".repeat(level + 1)).unwrap();
+            for field in &item.fields {
+                write!(
+                    out,
+                    "- <code>{}</code>{}",
+                    self.html_signature(&field.signature, Format::Markdown),
+                    private_markdown(field.is_pub),
+                )
+                .unwrap();
+                if let Some(docs) = &field.docs {
+                    write!(out, " — {}", docs.replace('\n', " ")).unwrap();
+                }
+                out.push('\n');
+            }
+            out.push('\n');
+        }
+
+        if !item.implements.is_empty() {
+            write!(out, "{} Implements\n\n", "#".repeat(level + 1)).unwrap();
+            for proto in &item.implements {
+                writeln!(
+                    out,
+                    "- <code>{}</code>",
+                    self.html_signature(proto, Format::Markdown)
+                )
+                .unwrap();
+            }
+            out.push('\n');
+        }
+
+        for (heading, funcs) in [
+            ("Required functions", &item.requirements),
+            ("Functions", &item.methods),
+        ] {
+            if funcs.is_empty() {
+                continue;
+            }
+
+            write!(out, "{} {heading}\n\n", "#".repeat(level + 1)).unwrap();
+            for func in funcs {
+                self.markdown_item(out, func, (level + 2).min(6));
+            }
+        }
+    }
+
+    /// A signature as HTML, which Markdown pages use as well
+    /// since code spans can't contain links.
+    fn html_signature(&self, signature: &Signature, format: Format) -> String {
+        let mut out = String::new();
+
+        for segment in signature {
+            match segment {
+                Segment::Text(text) => out.push_str(&escape(text)),
+                Segment::Link { text, link } => write!(
+                    out,
+                    "<a href=\"{}#{}\">{}</a>",
+                    self.page_name(link.module, format),
+                    link.anchor,
+                    escape(text)
+                )
+                .unwrap(),
+            }
+        }
+
+        out
+    }
+}
+
+/// Builds [Documentation] in two passes, so that items can
+/// link to types declared after them or in other files.
+#[derive(Default)]
+struct Builder {
+    modules: Vec<ModuleDoc>,
+    /// The items of each module, which are documented in the second pass.
+    items: Vec<Vec<ModuleItem>>,
+    /// Every class, proto and enum by name.
+    types: AHashMap<String, Vec<Link>>,
+    /// The `impl`s of each class.
+    impls: Vec<(usize, Impl)>,
+}
+
+impl Builder {
+    fn collect(
+        &mut self,
+        path: Vec<String>,
+        is_pub: bool,
+        docs: Option<String>,
+        items: Vec<ModuleItem>,
+    ) {
+        let module = self.modules.len();
+        self.modules.push(ModuleDoc {
+            path: path.clone(),
+            is_pub,
+            docs,
+            items: vec![],
+        });
+        self.items.push(items.clone());
+
+        for item in items {
+            let (kind, name) = match item {
+                ModuleItem::Decl(Decl::Class(class)) => (ItemKind::Class, class.name()),
+                ModuleItem::Decl(Decl::Proto(proto)) => (ItemKind::Proto, proto.name()),
+                ModuleItem::Decl(Decl::Enum(enumeration)) => (ItemKind::Enum, enumeration.name()),
+                ModuleItem::Decl(Decl::Module(nested)) => {
+                    if let Some(name) = nested.name() {
+                        let mut path = path.clone();
+                        path.push(name.text().to_string());
+                        self.collect(
+                            path,
+                            nested.is_pub(),
+                            nested.docs(),
+                            nested.items().collect(),
+                        );
+                    }
+                    continue;
+                }
+                ModuleItem::Impl(imp) => {
+                    self.impls.push((module, imp));
+                    continue;
+                }
+                _ => continue,
+            };
+
+            if let Some(name) = name {
+                let name = name.text().to_string();
+                let anchor = format!("{}.{name}", kind.as_str());
+                self.types
+                    .entry(name)
+                    .or_default()
+                    .push(Link { module, anchor });
+            }
+        }
+    }
+
+    fn build(mut self) -> Documentation {
+        for module in 0..self.modules.len() {
+            let items = std::mem::take(&mut self.items[module]);
+            let docs = items
+                .iter()
+                .filter_map(|item| match item {
+                    ModuleItem::Decl(decl) => self.decl(decl, module),
+                    ModuleItem::Impl(_) => None,
+                })
+                .collect();
+
+            self.modules[module].items = docs;
+        }
+
+        let pages = page_names(&self.modules);
+
+        Documentation {
+            modules: self.modules,
+            pages,
+        }
+    }
+
+    fn decl(&self, decl: &Decl, module: usize) -> Option<ItemDoc> {
+        match decl {
+            Decl::Class(class) => self.class(class, module),
+            Decl::Proto(proto) => self.proto(proto, module),
+            Decl::Enum(enumeration) => self.enumeration(enumeration, module),
+            Decl::Func(func) => self.func(func, module, ""),
+            Decl::Var(var) => self.var(var, module),
+            Decl::Module(_) | Decl::Import(_) => None,
+        }
+    }
+
+    fn class(&self, class: &Class, module: usize) -> Option<ItemDoc> {
+        let name = class.name()?.text().to_string();
+        let anchor = format!("class.{name}");
+        let link = Link {
+            module,
+            anchor: anchor.clone(),
+        };
+
+        let mut doc = ItemDoc::new(
+            ItemKind::Class,
+            name,
+            anchor.clone(),
+            class.is_pub(),
+            self.signature(class.syntax(), module, &[SyntaxKind::CLASS_BODY]),
+        );
+        doc.docs = class.docs();
+        doc.fields = class
+            .fields()
+            .filter_map(|f| self.field(&f, module))
+            .collect();
+
+        for (impl_module, imp) in &self.impls {
+            let target = imp.ty().and_then(|ty| match ty {
+                Type::Path(path) => self.resolve(&path, *impl_module),
+                _ => None,
+            });
+            if target.as_ref() != Some(&link) {
+                continue;
+            }
+
+            match imp.proto() {
+                Some(proto) => {
+                    doc.implements
+                        .push(self.signature(proto.syntax(), *impl_module, &[]))
+                }
+                None => doc.methods.extend(
+                    imp.funcs()
+                        .filter_map(|f| self.func(&f, *impl_module, &anchor)),
+                ),
+            }
+        }
+
+        Some(doc)
+    }
+
+    fn field(&self, field: &ClassField, module: usize) -> Option<FieldDoc> {
+        Some(FieldDoc {
+            name: field.name()?.text().to_string(),
+            is_pub: field.is_pub(),
+            signature: self.signature(field.syntax(), module, &[SyntaxKind::SEMICOLON]),
+            docs: field.docs(),
+        })
+    }
+
+    fn proto(&self, proto: &Proto, module: usize) -> Option<ItemDoc> {
+        let name = proto.name()?.text().to_string();
+        let anchor = format!("proto.{name}");
+
+        let mut doc = ItemDoc::new(
+            ItemKind::Proto,
+            name,
+            anchor.clone(),
+            proto.is_pub(),
+            self.signature(proto.syntax(), module, &[SyntaxKind::PROTO_BODY]),
+        );
+        doc.docs = proto.docs();
+
+        for func in proto.funcs() {
+            let Some(func_doc) = self.func(&func, module, &anchor) else {
+                continue;
+            };
+
+            match func.block() {
+                Some(_) => doc.methods.push(func_doc),
+                None => doc.requirements.push(func_doc),
+            }
+        }
+
+        Some(doc)
+    }
+
+    fn enumeration(&self, enumeration: &Enum, module: usize) -> Option<ItemDoc> {
+        let name = enumeration.name()?.text().to_string();
+        let anchor = format!("enum.{name}");
+
+        let mut doc = ItemDoc::new(
+            ItemKind::Enum,
+            name,
+            anchor,
+            enumeration.is_pub(),
+            self.signature(enumeration.syntax(), module, &[SyntaxKind::ENUM_BODY]),
+        );
+        doc.docs = enumeration.docs();
+        doc.fields = enumeration
+            .variants()
+            .filter_map(|v| self.variant(&v, module))
+            .collect();
+
+        Some(doc)
+    }
+
+    fn variant(&self, variant: &EnumVariant, module: usize) -> Option<FieldDoc> {
+        Some(FieldDoc {
+            name: variant.name()?.text().to_string(),
+            is_pub: true,
+            signature: self.signature(variant.syntax(), module, &[SyntaxKind::COMMA]),
+            docs: variant.docs(),
+        })
+    }
+
+    /// A function, with an anchor inside of `parent` if it's a method.
+    fn func(&self, func: &Func, module: usize, parent: &str) -> Option<ItemDoc> {
+        let name = func.name()?.text().to_string();
+        let anchor = match parent {
+            "" => format!("fun.{name}"),
+            parent => format!("{parent}.{name}"),
+        };
+
+        let mut doc = ItemDoc::new(
+            ItemKind::Func,
+            name,
+            anchor,
+            func.is_pub(),
+            self.signature(func.syntax(), module, &[SyntaxKind::FUNC_BODY]),
+        );
+        doc.docs = func.docs();
+
+        Some(doc)
+    }
+
+    fn var(&self, var: &Var, module: usize) -> Option<ItemDoc> {
+        let name = var.name()?.text().to_string();
+        let anchor = format!("var.{name}");
+
+        let mut doc = ItemDoc::new(
+            ItemKind::Var,
+            name,
+            anchor,
+            var.is_pub(),
+            self.signature(
+                var.syntax(),
+                module,
+                &[SyntaxKind::VAR_VALUE, SyntaxKind::SEMICOLON],
+            ),
+        );
+        doc.docs = var.docs();
+
+        Some(doc)
+    }
+
+    /// Find the type a path names, preferring the current module.
+    fn resolve(&self, path: &Path, module: usize) -> Option<Link> {
+        let names = path.names().map(|n| n.to_string()).collect::<Vec<_>>();
+        let (name, qualifier) = names.split_last()?;
+        let candidates = self.types.get(name)?;
+
+        candidates
+            .iter()
+            .find(|link| link.module == module && qualifier.is_empty())
+            .or_else(|| {
+                candidates
+                    .iter()
+                    .find(|link| self.modules[link.module].path.ends_with(qualifier))
+            })
+            .cloned()
+    }
+
+    /// The tokens of a declaration without its trivia, or the children of
+    /// `node` with a kind in `skip`, with the last name of each path linked.
+    fn signature(&self, node: &SyntaxNode, module: usize, skip: &[SyntaxKind]) -> Signature {
+        let mut signature = vec![];
+        let mut text = String::new();
+        let mut previous: Option<SyntaxToken> = None;
+        let mut preorder = node.preorder_with_tokens();
+
+        while let Some(event) = preorder.next() {
+            let WalkEvent::Enter(element) = event else {
+                continue;
+            };
+
+            let is_child = element.parent().as_ref() == Some(node);
+            if is_child && skip.contains(&element.kind()) {
+                if element.as_node().is_some() {
+                    preorder.skip_subtree();
+                }
+                continue;
+            }
+
+            let NodeOrToken::Token(token) = element else {
+                continue;
+            };
+            if token.kind().is_trivia() {
+                continue;
+            }
+
+            if previous.is_some_and(|p| needs_space(&p, &token)) {
+                text.push(' ');
+            }
+
+            match self.path_link(&token, module) {
+                Some(link) => {
+                    if !text.is_empty() {
+                        signature.push(Segment::Text(std::mem::take(&mut text)));
+                    }
+                    signature.push(Segment::Link {
+                        text: token.text().to_string(),
+                        link,
+                    });
+                }
+                None => text.push_str(token.text()),
+            }
+
+            previous = Some(token);
+        }
+
+        if !text.is_empty() {
+            signature.push(Segment::Text(text));
+        }
+
+        signature
+    }
+
+    /// Where the last name of a path links to.
+    fn path_link(&self, token: &SyntaxToken, module: usize) -> Option<Link> {
+        let segment = token.parent()?.parent().and_then(PathSegment::cast)?;
+        let path = segment.syntax().parent().and_then(Path::cast)?;
+
+        if path.path_segments().last()? != segment {
+            return None;
+        }
+
+        self.resolve(&path, module)
+    }
+}
+
+/// A unique page name for each module, like `a.b` for `a::b`.
+/// Names that are taken get a number after them, and `index`
+/// is always taken by the index page.
+fn page_names(modules: &[ModuleDoc]) -> Vec<String> {
+    let mut taken = AHashSet::from_iter(["index".to_string()]);
+
+    modules
+        .iter()
+        .map(|module| {
+            let name = module.path.join(".");
+            let name = (1..)
+                .map(|i| match i {
+                    1 => name.clone(),
+                    i => format!("{name}-{i}"),
+                })
+                .find(|name| !taken.contains(name))
+                .unwrap();
+
+            taken.insert(name.clone());
+            name
+        })
+        .collect()
+}
+
+fn escape(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+
+    for ch in text.chars() {
+        match ch {
+            '&' => out.push_str("&amp;"),
+            '<' => out.push_str("&lt;"),
+            '>' => out.push_str("&gt;"),
+            '"' => out.push_str("&quot;"),
+            ch => out.push(ch),
+        }
+    }
+
+    out
+}
+
+fn html_header(title: &str) -> String {
+    format!(
+        "<!DOCTYPE html>
+<html>
+<head>
+<meta charset=\"utf-8\">
+<title>{}</title>
+<style>
+body {{ font-family: sans-serif; max-width: 60em; margin: auto; }}
+pre {{ background: #f4f4f4; padding: 0.5em; }}
+.private {{ color: #888; font-size: small; }}
+</style>
+</head>
+<body>
+",
+        escape(title)
+    )
+}
+
+/// Doc comments as paragraphs of plain text.
+fn html_docs(docs: Option<&str>) -> String {
+    let Some(docs) = docs else {
+        return String::new();
+    };
+
+    docs.split("\n\n")
+        .filter(|p| !p.trim().is_empty())
+        .map(|p| format!("<p>{}</p>\n", escape(p.trim())))
+        .collect()
+}
+
+/// Doc comments are written in Markdown already.
+fn markdown_docs(docs: Option<&str>) -> String {
+    match docs {
+        Some(docs) => format!("{docs}\n\n"),
+        None => String::new(),
+    }
+}
+
+fn private_html(is_pub: bool) -> &'static str {
+    match is_pub {
+        true => "",
+        false => " <span class=\"private\">private</span>",
+    }
+}
+
+fn private_markdown(is_pub: bool) -> &'static str {
+    match is_pub {
+        true => "",
+        false => " *(private)*",
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use guano_common::source_map::SourceMap;
+
+    use super::{Documentation, Format, ItemKind, Link, Segment};
+
+    fn docs() -> Documentation {
+        let mut map = SourceMap::new();
+        map.add(
+            "src/shapes.guano",
+            "//! Shapes and their areas.
+
+/// Something with an area.
+pub proto Shape {
+    /// The area.
+    fun area() -> float;
+
+    fun describe() -> string {
+        return \"shape\";
+    }
+}
+
+/// A point.
+pub class Point {
+    /// Across.
+    pub x: float;
+    y: float;
+}
+
+/// Which way a shape faces.
+pub enum Facing {
+    /// Towards the top.
+    Up,
+    Down,
+}
+
+pub module nested {
+    pub fun origin() -> Point;
+}
+",
+        );
+        map.add(
+            "src/main.guano",
+            "import * from shapes;
+
+impl Shape on Point {
+    fun area() -> float {
+        return 0.0;
+    }
+}
+
+impl Point {
+    /// Move a point.
+    pub fun moved(offset: Point,
+                      scale: float) -> Point;
+}
+
+let ORIGIN: shapes::Point = nested::origin();
+",
+        );
+
+        Documentation::from_source_map(&map)
+    }
+
+    #[test]
+    fn test_items() {
+        let docs = docs();
+        let modules = docs.modules();
+
+        let names = modules.iter().map(|m| m.name()).collect::<Vec<_>>();
+        assert_eq!(names, ["shapes", "shapes::nested", "main"]);
+        assert_eq!(modules[0].docs.as_deref(), Some("Shapes and their areas."));
+
+        let shape = &modules[0].items[0];
+        assert_eq!(shape.kind, ItemKind::Proto);
+        assert_eq!(shape.requirements[0].name, "area");
+        assert_eq!(shape.requirements[0].docs.as_deref(), Some("The area."));
+        assert_eq!(shape.methods[0].name, "describe");
+
+        let point = &modules[0].items[1];
+        assert_eq!(point.docs.as_deref(), Some("A point."));
+        assert_eq!(point.fields.len(), 2);
+        assert!(!point.fields[1].is_pub);
+        assert_eq!(point.methods[0].name, "moved");
+        assert_eq!(point.methods[0].anchor, "class.Point.moved");
+
+        let facing = &modules[0].items[2];
+        assert_eq!(facing.kind, ItemKind::Enum);
+        assert_eq!(facing.docs.as_deref(), Some("Which way a shape faces."));
+        assert_eq!(facing.fields[0].docs.as_deref(), Some("Towards the top."));
+        assert_eq!(facing.fields[1].docs, None);
+
+        // Multi-line signatures end up on one line, without bodies.
+        let link = Link {
+            module: 0,
+            anchor: "class.Point".to_string(),
+        };
+        assert_eq!(
+            point.methods[0].signature,
+            [
+                Segment::Text("pub fun moved(offset: ".to_string()),
+                Segment::Link {
+                    text: "Point".to_string(),
+                    link: link.clone(),
+                },
+                Segment::Text(", scale: float) -> ".to_string()),
+                Segment::Link {
+                    text: "Point".to_string(),
+                    link,
+                },
+            ]
+        );
+
+        assert_eq!(
+            point.implements,
+            [vec![Segment::Link {
+                text: "Shape".to_string(),
+                link: Link {
+                    module: 0,
+                    anchor: "proto.Shape".to_string(),
+                },
+            }]]
+        );
+    }
+
+    #[test]
+    fn test_page_names() {
+        let mut map = SourceMap::new();
+        map.add("lib/a/util.guano", "pub class Thing;");
+        map.add("lib/b/util.guano", "pub class Thing;");
+        map.add("lib/a.util.guano", "");
+        map.add("lib/index.guano", "let THING: b::util::Thing = nil;");
+        let docs = Documentation::from_source_map(&map);
+
+        let names = docs.modules().iter().map(|m| m.name()).collect::<Vec<_>>();
+        assert_eq!(names, ["a::util", "b::util", "a.util", "index"]);
+
+        let pages = docs.pages(Format::Html);
+        let paths = pages.iter().map(|p| &*p.path).collect::<Vec<_>>();
+        assert_eq!(
+            paths,
+            [
+                "index.html",
+                "a.util.html",
+                "b.util.html",
+                "a.util-2.html",
+                "index-2.html"
+            ]
+        );
+
+        let index = &pages[4].content;
+        assert!(
+            index.contains("b::util::<a href=\"b.util.html#class.Thing\">Thing</a>"),
+            "{index}"
+        );
+    }
+
+    #[test]
+    fn test_pages() {
+        let docs = docs();
+
+        let html = docs.pages(Format::Html);
+        let paths = html.iter().map(|p| &*p.path).collect::<Vec<_>>();
+        assert_eq!(
+            paths,
+            [
+                "index.html",
+                "shapes.html",
+                "shapes.nested.html",
+                "main.html"
+            ]
+        );
+
+        let main = &html[3].content;
+        assert!(
+            main.contains("let ORIGIN: shapes::<a href=\"shapes.html#class.Point\">Point</a>"),
+            "{main}"
+        );
+
+        let shapes = &html[1].content;
+        assert!(shapes.contains("<section id=\"class.Point\">"), "{shapes}");
+        assert!(
+            shapes.contains("<a href=\"shapes.nested.html\"><code>nested</code></a>"),
+            "{shapes}"
+        );
+        assert!(
+            shapes.contains("<p>Something with an area.</p>"),
+            "{shapes}"
+        );
+
+        let markdown = docs.pages(Format::Markdown);
+        let shapes = &markdown[1].content;
+        assert!(
+            shapes
+                .starts_with("[Index](index.md)\n\n# Module `shapes`\n\nShapes and their areas.\n"),
+            "{shapes}"
+        );
+        assert!(
+            shapes.contains("<a id=\"class.Point\"></a>\n\n### class `Point`\n"),
+            "{shapes}"
+        );
+        assert!(
+            shapes.contains("- <code>y: float</code> *(private)*\n"),
+            "{shapes}"
+        );
+        assert!(
+            shapes.contains("- <code><a href=\"shapes.md#proto.Shape\">Shape</a></code>\n"),
+            "{shapes}"
+        );
+    }
+}
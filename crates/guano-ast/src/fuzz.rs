@@ -0,0 +1,563 @@
+use std::panic::{self, AssertUnwindSafe};
+
+use guano_common::rowan::{ast::AstNode, TextRange, TextSize};
+use guano_syntax::{
+    consts::{Keyword, Punctuation},
+    nodes::{BinaryOp, Decl, Expr, ImportBody, ModuleItem, Pattern, Statement, Type},
+    SyntaxNode,
+};
+use thiserror::Error;
+
+use crate::parsing::{
+    combinators::errors::{CombinatorError, ExpectError},
+    error::{Error, ErrorKind},
+    parsers::expression::operator::infix::BinaryExt,
+};
+
+/// A guarantee of the parser that didn't hold for some input.
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum Violation {
+    #[error("The parser panicked: {0}")]
+    Panic(String),
+    #[error("The syntax tree's text {text:?} doesn't match the input")]
+    Lossy { text: String },
+    #[error("The syntax tree covers {0:?} instead of the whole input")]
+    Coverage(TextRange),
+    #[error("The error {message:?} at {span:?} lies outside of the input")]
+    ErrorSpan { span: TextRange, message: String },
+}
+
+/// Parse `source`, and check that the parser:
+/// - doesn't panic, even when the tree is read through the typed AST,
+/// - builds a tree whose text is exactly the input,
+/// - only reports errors that point inside of the input.
+pub fn check(source: &str) -> Result<(), Violation> {
+    panic::catch_unwind(AssertUnwindSafe(|| check_parse(source))).unwrap_or_else(|payload| {
+        let message = payload
+            .downcast_ref::<&str>()
+            .map(|s| s.to_string())
+            .or_else(|| payload.downcast_ref::<String>().cloned())
+            .unwrap_or_default();
+
+        Err(Violation::Panic(message))
+    })
+}
+
+fn check_parse(source: &str) -> Result<(), Violation> {
+    let (context, file) = crate::parse_file(source);
+    let whole = TextRange::up_to(TextSize::of(source));
+
+    let text = file.syntax().to_string();
+    if text != source {
+        return Err(Violation::Lossy { text });
+    }
+
+    let range = file.syntax().text_range();
+    if range != whole {
+        return Err(Violation::Coverage(range));
+    }
+
+    check_ast(file.syntax());
+
+    let mut errors = vec![];
+    for error in context.errors() {
+        error_spans(error, &mut errors);
+    }
+
+    let lex_errors = context
+        .lex_errors()
+        .iter()
+        .map(|e| (e.range, e.kind.to_string()));
+
+    for (span, message) in errors.into_iter().chain(lex_errors) {
+        if !whole.contains_range(span) {
+            return Err(Violation::ErrorSpan { span, message });
+        }
+    }
+
+    Ok(())
+}
+
+/// Go through every node as its typed AST node, which
+/// panics if the tree doesn't have the shape the AST expects.
+fn check_ast(root: &SyntaxNode) {
+    for node in root.descendants() {
+        ModuleItem::cast(node.clone());
+        Decl::cast(node.clone());
+        Statement::cast(node.clone());
+        Expr::cast(node.clone());
+        Type::cast(node.clone());
+        Pattern::cast(node.clone());
+        ImportBody::cast(node.clone());
+
+        if let Some(op) = BinaryOp::cast(node) {
+            op.kind();
+        }
+    }
+}
+
+/// Collect the spans of an error, and of the errors inside of it.
+fn error_spans(error: &Error<'_>, spans: &mut Vec<(TextRange, String)>) {
+    if let Some(span) = error.span {
+        spans.push((span, error.to_string()));
+    }
+
+    if let ErrorKind::Combinator(CombinatorError::Expect(ExpectError::Error(inner))) = &error.kind {
+        error_spans(inner, spans);
+    }
+}
+
+/// Creates inputs for [check], from a seed so that failures can be reproduced.
+#[derive(Debug, Clone)]
+pub struct Generator {
+    state: u64,
+}
+
+const NAMES: &[&str] = &["a", "b", "value", "_", "Point", "self_", "x1", "é"];
+const TYPES: &[&str] = &[
+    "int",
+    "uint",
+    "float",
+    "string",
+    "boolean",
+    "Point",
+    "math::Vec",
+];
+const LITERALS: &[&str] = &[
+    "0",
+    "1",
+    "100",
+    "0x1F",
+    "0b101",
+    "1.5",
+    "2.0",
+    "'c'",
+    "'\\n'",
+    "\"\"",
+    "\"text\"",
+    "\"a {b} c\"",
+    "true",
+    "false",
+    "nil",
+];
+const BINARY: &[&str] = &[
+    "+", "-", "*", "/", "%", "**", "==", "!=", "<", "<=", ">", ">=", "&&", "||", "&", "|", "^",
+    "<<", ">>", "..", "..=", "=", "+=", "-=",
+];
+const TRIVIA: &[&str] = &[
+    " ",
+    "\n",
+    "\t",
+    "\r\n",
+    "// comment\n",
+    "/* block */",
+    "/// doc\n",
+    "//! inner\n",
+    "/** doc */",
+];
+
+impl Generator {
+    #[inline]
+    pub fn new(seed: u64) -> Self {
+        // Xorshift gets stuck on a state of zero.
+        Self {
+            state: seed.wrapping_mul(0x9E37_79B9_7F4A_7C15) | 1,
+        }
+    }
+
+    fn next(&mut self) -> u64 {
+        self.state ^= self.state << 13;
+        self.state ^= self.state >> 7;
+        self.state ^= self.state << 17;
+        self.state
+    }
+
+    fn below(&mut self, n: usize) -> usize {
+        (self.next() % n as u64) as usize
+    }
+
+    fn chance(&mut self, percent: usize) -> bool {
+        self.below(100) < percent
+    }
+
+    fn pick<T: Copy>(&mut self, items: &[T]) -> T {
+        items[self.below(items.len())]
+    }
+
+    /// Arbitrary characters, weighted towards ones that mean something to the lexer.
+    pub fn random(&mut self, len: usize) -> String {
+        const INTERESTING: &[char] = &[
+            '{', '}', '(', ')', '[', ']', '<', '>', '"', '\'', '/', '*', '\\', ':', ';', ',', '.',
+            '=', '_', '0', 'x', 'b', ' ', '\n', '\r', '\t', 'é', '😀', '\0',
+        ];
+
+        (0..len)
+            .map(|_| match self.below(3) {
+                0 => self.pick(INTERESTING),
+                1 => (b' ' + self.below(95) as u8) as char,
+                _ => char::from_u32(self.below(0x800) as u32).unwrap_or('?'),
+            })
+            .collect()
+    }
+
+    /// A sequence of valid tokens in no particular order.
+    pub fn tokens(&mut self, len: usize) -> String {
+        let mut out = String::new();
+
+        for _ in 0..len {
+            match self.below(5) {
+                0 => out.push_str(self.pick(Keyword::ALL).as_str()),
+                1 => out.push_str(self.pick(Punctuation::ALL).as_str()),
+                2 => out.push_str(self.pick(NAMES)),
+                3 => out.push_str(self.pick(LITERALS)),
+                _ => out.push_str(self.pick(TRIVIA)),
+            }
+
+            if self.chance(60) {
+                out.push(' ');
+            }
+        }
+
+        out
+    }
+
+    /// A file built from the grammar, which may still have a few mistakes in it.
+    pub fn file(&mut self) -> String {
+        let mut out = String::new();
+
+        for _ in 0..self.below(4) + 1 {
+            self.decl(&mut out, 3);
+            self.trivia(&mut out);
+        }
+
+        out
+    }
+
+    /// Delete, duplicate or insert a few random pieces of `source`.
+    pub fn mutate(&mut self, source: &str) -> String {
+        let mut out = source.to_string();
+
+        for _ in 0..self.below(3) + 1 {
+            let boundaries = (0..=out.len())
+                .filter(|&i| out.is_char_boundary(i))
+                .collect::<Vec<_>>();
+            let mut start = self.pick(&boundaries);
+            let mut end = self.pick(&boundaries);
+            if start > end {
+                std::mem::swap(&mut start, &mut end);
+            }
+
+            match self.below(3) {
+                0 => out.replace_range(start..end, ""),
+                1 => {
+                    let copy = out[start..end].to_string();
+                    out.insert_str(end, &copy);
+                }
+                _ => {
+                    let len = self.below(4) + 1;
+                    let tokens = self.tokens(len);
+                    out.insert_str(start, &tokens);
+                }
+            }
+        }
+
+        out
+    }
+
+    fn trivia(&mut self, out: &mut String) {
+        out.push_str(self.pick(TRIVIA));
+    }
+
+    /// Insert a random token some of the time, to exercise error recovery.
+    fn noise(&mut self, out: &mut String) {
+        if self.chance(3) {
+            let tokens = self.tokens(1);
+            out.push_str(&tokens);
+        }
+    }
+
+    fn decl(&mut self, out: &mut String, depth: usize) {
+        if self.chance(30) {
+            out.push_str("pub ");
+        }
+
+        match self.below(6) {
+            0 => {
+                out.push_str("let ");
+                out.push_str(self.pick(NAMES));
+                if self.chance(50) {
+                    out.push_str(": ");
+                    self.ty(out);
+                }
+                out.push_str(" = ");
+                self.expr(out, depth);
+                out.push(';');
+            }
+            1 => {
+                out.push_str("fun ");
+                out.push_str(self.pick(NAMES));
+                out.push('(');
+                for i in 0..self.below(3) {
+                    if i > 0 {
+                        out.push_str(", ");
+                    }
+                    out.push_str(self.pick(NAMES));
+                    out.push_str(": ");
+                    self.ty(out);
+                }
+                out.push(')');
+                if self.chance(50) {
+                    out.push_str(" -> ");
+                    self.ty(out);
+                }
+                out.push(' ');
+                self.block(out, depth);
+            }
+            2 => {
+                out.push_str("class ");
+                out.push_str(self.pick(NAMES));
+                out.push_str(" {\n");
+                for _ in 0..self.below(3) {
+                    out.push_str(self.pick(NAMES));
+                    out.push_str(": ");
+                    self.ty(out);
+                    out.push_str(";\n");
+                }
+                out.push('}');
+            }
+            3 => {
+                out.push_str("import ");
+                out.push_str(self.pick(NAMES));
+                out.push(';');
+            }
+            4 => {
+                out.push_str("enum ");
+                out.push_str(self.pick(NAMES));
+                out.push_str(" { ");
+                for _ in 0..self.below(3) {
+                    out.push_str(self.pick(NAMES));
+                    out.push_str(", ");
+                }
+                out.push('}');
+            }
+            _ => {
+                out.push_str("module ");
+                out.push_str(self.pick(NAMES));
+                out.push_str(" {\n");
+                if depth > 0 {
+                    self.decl(out, depth - 1);
+                }
+                out.push_str("\n}");
+            }
+        }
+
+        self.noise(out);
+    }
+
+    fn ty(&mut self, out: &mut String) {
+        out.push_str(self.pick(TYPES));
+
+        if self.chance(20) {
+            out.push('?');
+        }
+    }
+
+    fn block(&mut self, out: &mut String, depth: usize) {
+        out.push('{');
+        self.trivia(out);
+
+        for _ in 0..self.below(3) {
+            if depth > 0 && self.chance(30) {
+                self.decl(out, depth - 1);
+            } else {
+                self.expr(out, depth);
+                out.push(';');
+            }
+            self.trivia(out);
+        }
+
+        out.push('}');
+    }
+
+    fn expr(&mut self, out: &mut String, depth: usize) {
+        if depth == 0 {
+            return self.atom(out);
+        }
+
+        match self.below(9) {
+            0 | 1 => self.atom(out),
+            2 => {
+                self.expr(out, depth - 1);
+                out.push(' ');
+                out.push_str(self.pick(BINARY));
+                out.push(' ');
+                self.expr(out, depth - 1);
+            }
+            3 => {
+                out.push_str(self.pick(&["-", "!", "~"]));
+                self.expr(out, depth - 1);
+            }
+            4 => {
+                self.atom(out);
+                match self.below(4) {
+                    0 => {
+                        out.push('.');
+                        out.push_str(self.pick(NAMES));
+                    }
+                    1 => {
+                        out.push('[');
+                        self.expr(out, depth - 1);
+                        out.push(']');
+                    }
+                    2 => {
+                        out.push('(');
+                        self.expr(out, depth - 1);
+                        out.push(')');
+                    }
+                    _ => {
+                        out.push_str(" as ");
+                        self.ty(out);
+                    }
+                }
+            }
+            5 => {
+                out.push_str("if ");
+                self.expr(out, depth - 1);
+                out.push(' ');
+                self.block(out, depth - 1);
+                if self.chance(50) {
+                    out.push_str(" else ");
+                    self.block(out, depth - 1);
+                }
+            }
+            6 => {
+                out.push_str(self.pick(&["while ", "for x in "]));
+                self.expr(out, depth - 1);
+                out.push(' ');
+                self.block(out, depth - 1);
+            }
+            7 => {
+                out.push_str("match ");
+                self.expr(out, depth - 1);
+                out.push_str(" {\n");
+                for _ in 0..self.below(3) {
+                    self.atom(out);
+                    out.push_str(" => ");
+                    self.expr(out, depth - 1);
+                    out.push_str(",\n");
+                }
+                out.push('}');
+            }
+            _ => {
+                out.push('[');
+                for _ in 0..self.below(3) {
+                    self.expr(out, depth - 1);
+                    out.push_str(", ");
+                }
+                out.push(']');
+            }
+        }
+
+        self.noise(out);
+    }
+
+    fn atom(&mut self, out: &mut String) {
+        if self.chance(50) {
+            out.push_str(self.pick(NAMES));
+        } else {
+            out.push_str(self.pick(LITERALS));
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{check, Generator};
+
+    /// Set `GUANO_FUZZ_CASES` to run more cases than the default.
+    fn cases() -> u64 {
+        std::env::var("GUANO_FUZZ_CASES")
+            .ok()
+            .and_then(|cases| cases.parse().ok())
+            .unwrap_or(300)
+    }
+
+    #[track_caller]
+    fn assert_holds(source: &str) {
+        if let Err(violation) = check(source) {
+            panic!("{violation}\nInput: {source:?}");
+        }
+    }
+
+    #[test]
+    fn test_random() {
+        for seed in 0..cases() {
+            let mut generator = Generator::new(seed);
+            let len = generator.below(64);
+            assert_holds(&generator.random(len));
+        }
+    }
+
+    #[test]
+    fn test_tokens() {
+        for seed in 0..cases() {
+            let mut generator = Generator::new(seed);
+            let len = generator.below(32);
+            assert_holds(&generator.tokens(len));
+        }
+    }
+
+    #[test]
+    fn test_grammar() {
+        for seed in 0..cases() {
+            let mut generator = Generator::new(seed);
+            let source = generator.file();
+            assert_holds(&source);
+
+            // Every prefix is an unfinished edit of a valid-ish file.
+            let cut = generator.below(source.len() + 1);
+            if source.is_char_boundary(cut) {
+                assert_holds(&source[..cut]);
+            }
+        }
+    }
+
+    #[test]
+    fn test_mutations() {
+        let source = include_str!("../../../main.guano");
+        assert_holds(source);
+
+        for seed in 0..cases() / 10 {
+            assert_holds(&Generator::new(seed).mutate(source));
+        }
+    }
+
+    #[test]
+    fn test_edge_cases() {
+        for source in [
+            "",
+            " ",
+            "\n",
+            "{",
+            "}",
+            "\"",
+            "'",
+            "/*",
+            "<<",
+            "a.",
+            "a as",
+            "fun",
+            "\0",
+            "let a = b + c * d;",
+        ] {
+            assert_holds(source);
+        }
+
+        assert!(matches!(check("a"), Ok(())));
+        assert_eq!(
+            Generator::new(7).file(),
+            Generator::new(7).file(),
+            "Generators should be deterministic"
+        );
+    }
+}
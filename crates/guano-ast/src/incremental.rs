@@ -0,0 +1,476 @@
+use guano_common::rowan::{ast::AstNode, GreenNode, GreenToken, NodeOrToken, TextRange, TextSize};
+use guano_syntax::{nodes::SourceFile, Child, SyntaxKind, SyntaxNode};
+
+use crate::parsing::{
+    combinators::errors::{CombinatorError, ExpectError},
+    error::{Error, ErrorKind, Res},
+    lexer::{self, LexError, Token},
+    parsers::{
+        declaration::{class::class_block, module::module_body},
+        expression::block::block,
+    },
+    ParseContext,
+};
+
+/// Replace the text in `delete` with `insert`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TextEdit {
+    pub delete: TextRange,
+    pub insert: String,
+}
+
+impl TextEdit {
+    #[inline]
+    pub fn replace(delete: TextRange, insert: impl Into<String>) -> Self {
+        Self {
+            delete,
+            insert: insert.into(),
+        }
+    }
+
+    #[inline]
+    pub fn insert(offset: TextSize, insert: impl Into<String>) -> Self {
+        Self::replace(TextRange::empty(offset), insert)
+    }
+
+    #[inline]
+    pub fn delete(delete: TextRange) -> Self {
+        Self::replace(delete, "")
+    }
+
+    /// Apply the edit to `text`.
+    pub fn apply(&self, text: &mut String) {
+        text.replace_range(std::ops::Range::<usize>::from(self.delete), &self.insert);
+    }
+
+    /// How much longer the text gets.
+    fn delta(&self) -> i64 {
+        self.insert.len() as i64 - u32::from(self.delete.len()) as i64
+    }
+
+    /// Apply the edit to `text`, which starts at `offset` in the whole source.
+    fn apply_at(&self, text: &str, offset: TextSize) -> String {
+        let mut text = text.to_owned();
+        let edit = TextEdit::replace(self.delete - offset, self.insert.clone());
+        edit.apply(&mut text);
+
+        text
+    }
+}
+
+/// A parsed file, with all of its errors, that can be updated by edits.
+#[derive(Debug, Clone)]
+pub struct Parse {
+    file: SourceFile,
+    errors: Vec<Error<'static>>,
+    lex_errors: Vec<LexError>,
+}
+
+type NodeParser = for<'source> fn(&mut ParseContext<'source>) -> Res<'source, Child>;
+
+impl Parse {
+    /// Parse an entire file from scratch.
+    pub fn new(source: &str) -> Self {
+        let (context, file) = crate::parse_file(source);
+
+        Self {
+            file,
+            errors: context.errors().iter().map(|e| static_error(e)).collect(),
+            lex_errors: context.lex_errors().to_vec(),
+        }
+    }
+
+    #[inline]
+    pub fn file(&self) -> &SourceFile {
+        &self.file
+    }
+
+    #[inline]
+    pub fn errors(&self) -> &[Error<'static>] {
+        &self.errors
+    }
+
+    #[inline]
+    pub fn lex_errors(&self) -> &[LexError] {
+        &self.lex_errors
+    }
+
+    /// The text of the file.
+    #[inline]
+    pub fn text(&self) -> String {
+        self.file.syntax().to_string()
+    }
+
+    /// Parse the file again after an edit.
+    ///
+    /// Only the token, or the smallest `Block`, `ClassBlock` or `ModuleBody`
+    /// that contains the edit is reparsed, and spliced into the old tree.
+    /// Edits that could change anything outside of those, like
+    /// edits to an item's signature, reparse the entire file.
+    pub fn reparse(&self, edit: &TextEdit) -> Parse {
+        self.reparse_token(edit)
+            .or_else(|| self.reparse_node(edit))
+            .unwrap_or_else(|| {
+                let mut text = self.text();
+                edit.apply(&mut text);
+
+                Parse::new(&text)
+            })
+    }
+
+    fn reparse_token(&self, edit: &TextEdit) -> Option<Parse> {
+        use SyntaxKind::*;
+
+        let token = match self.file.syntax().covering_element(edit.delete) {
+            NodeOrToken::Token(token) => token,
+            NodeOrToken::Node(_) => return None,
+        };
+
+        // Fragments and punctuation depend on their surroundings.
+        if !matches!(
+            token.kind(),
+            IDEN | WHITESPACE
+                | COMMENT
+                | DOC_COMMENT
+                | INNER_DOC_COMMENT
+                | LIT_INTEGER
+                | LIT_FLOAT
+                | LIT_STRING
+                | LIT_CHAR
+        ) {
+            return None;
+        }
+
+        let range = token.text_range();
+        let text = edit.apply_at(token.text(), range.start());
+
+        // Errors inside of the token could go away or change with it.
+        let overlaps = |span: TextRange| span.intersect(range).is_some();
+        if self.errors.iter().filter_map(|e| e.span).any(overlaps)
+            || self.lex_errors.iter().any(|e| overlaps(e.range))
+        {
+            return None;
+        }
+
+        // `by` is only a keyword after a range, so it changes the tree around it.
+        if token.parent().is_some_and(|p| p.kind() == RANGE_STEP)
+            || token.text() == "by"
+            || text == "by"
+        {
+            return None;
+        }
+
+        // The new token can't merge with, or split off from its neighbors.
+        let prev = token.prev_token();
+        let next = token.next_token();
+        let prev_text = prev.as_ref().map_or("", |t| t.text());
+        let next_text = next.as_ref().map_or("", |t| t.text());
+
+        let lexed = lexer::lex(&format!("{prev_text}{text}{next_text}"));
+        let expected = prev
+            .iter()
+            .map(|t| (t.kind(), prev_text.len()))
+            .chain([(token.kind(), text.len())])
+            .chain(next.iter().map(|t| (t.kind(), next_text.len())))
+            .collect::<Vec<_>>();
+        let found = lexed
+            .tokens
+            .iter()
+            .map(|t| (t.kind, usize::from(t.range.len())))
+            .collect::<Vec<_>>();
+
+        if !lexed.errors.is_empty() || found != expected {
+            return None;
+        }
+
+        let green = token.replace_with(GreenToken::new(token.kind().into(), &text));
+        self.splice(edit, range, green, vec![])
+    }
+
+    fn reparse_node(&self, edit: &TextEdit) -> Option<Parse> {
+        let node = match self.file.syntax().covering_element(edit.delete) {
+            NodeOrToken::Node(node) => node,
+            NodeOrToken::Token(token) => token.parent()?,
+        };
+
+        // The braces have to stay in place, or the node could end somewhere else.
+        let (node, parser) = node.ancestors().find_map(|node| {
+            let range = node.text_range();
+            let parser: NodeParser = match node.kind() {
+                SyntaxKind::BLOCK => block,
+                SyntaxKind::CLASS_BLOCK => class_block,
+                SyntaxKind::MODULE_BODY => module_body,
+                _ => return None,
+            };
+
+            let is_inside = range.start() < edit.delete.start() && edit.delete.end() < range.end();
+            is_inside.then_some((node, parser))
+        })?;
+
+        let range = node.text_range();
+        let text = edit.apply_at(&node.to_string(), range.start());
+
+        let mut context = ParseContext::new(&text);
+        if !context.lex_errors().is_empty() || !is_balanced(context.tokens()) {
+            return None;
+        }
+
+        let green = match parser(&mut context) {
+            Ok(NodeOrToken::Node(green)) if context.is_eof() => green,
+            _ => return None,
+        };
+
+        let shift = |span: TextRange| span + range.start();
+        let errors = context
+            .errors()
+            .iter()
+            .map(|e| map_spans(&static_error(e), &shift))
+            .collect();
+
+        let green = node.replace_with(green);
+        self.splice(edit, range, green, errors)
+    }
+
+    /// Build the new parse from the new root, keeping the old
+    /// errors that are outside of the reparsed `range`.
+    fn splice(
+        &self,
+        edit: &TextEdit,
+        range: TextRange,
+        root: GreenNode,
+        mut errors: Vec<Error<'static>>,
+    ) -> Option<Parse> {
+        let delta = edit.delta();
+        let moved = |span| move_span(span, range, delta);
+        let mut lex_errors = vec![];
+
+        for error in &self.errors {
+            match error.span.map(moved) {
+                Some(Moved::Overlapping) => return None,
+                Some(Moved::Removed) => {}
+                _ => errors.push(map_spans(error, &|span| match moved(span) {
+                    Moved::Kept(span) => span,
+                    _ => span,
+                })),
+            }
+        }
+
+        for error in &self.lex_errors {
+            match moved(error.range) {
+                Moved::Overlapping => return None,
+                Moved::Removed => {}
+                Moved::Kept(range) => lex_errors.push(LexError {
+                    range,
+                    kind: error.kind,
+                }),
+            }
+        }
+
+        errors.sort_by_key(|e| e.span.map(|s| s.start()));
+
+        let file = SourceFile::cast(SyntaxNode::new_root(root))?;
+
+        Some(Parse {
+            file,
+            errors,
+            lex_errors,
+        })
+    }
+}
+
+/// Where a span of the old text ends up after reparsing `range`.
+enum Moved {
+    Kept(TextRange),
+    /// The span was inside of the reparsed range, so it's gone.
+    Removed,
+    /// The span was partly inside of the reparsed range,
+    /// so it's unclear where it should go.
+    Overlapping,
+}
+
+fn move_span(span: TextRange, range: TextRange, delta: i64) -> Moved {
+    let offset = |o: TextSize| TextSize::from((u32::from(o) as i64 + delta) as u32);
+
+    if span.end() <= range.start() {
+        Moved::Kept(span)
+    } else if span.start() >= range.end() {
+        Moved::Kept(TextRange::new(offset(span.start()), offset(span.end())))
+    } else if range.contains_range(span) {
+        Moved::Removed
+    } else {
+        Moved::Overlapping
+    }
+}
+
+/// Do the tokens start with a `{`, that is only closed by the last token?
+fn is_balanced(tokens: &[Token]) -> bool {
+    if tokens.first().map(|t| t.kind) != Some(SyntaxKind::LEFT_CURLY) {
+        return false;
+    }
+
+    let mut depth = 0usize;
+
+    for (i, token) in tokens.iter().enumerate() {
+        match token.kind {
+            SyntaxKind::LEFT_CURLY => depth += 1,
+            SyntaxKind::RIGHT_CURLY => depth -= 1,
+            _ => continue,
+        }
+
+        if depth == 0 {
+            return i == tokens.len() - 1;
+        }
+    }
+
+    false
+}
+
+fn static_error(error: &Error) -> Error<'static> {
+    error.clone().to_static()
+}
+
+/// Apply `f` to every span of an error, including the ones nested inside of it.
+fn map_spans(error: &Error<'static>, f: &dyn Fn(TextRange) -> TextRange) -> Error<'static> {
+    let kind = match &error.kind {
+        ErrorKind::Combinator(CombinatorError::Expect(ExpectError::Error(inner))) => {
+            let inner = ExpectError::Error(Box::new(map_spans(inner, f)));
+            ErrorKind::Combinator(CombinatorError::Expect(inner))
+        }
+        kind => kind.clone(),
+    };
+
+    Error {
+        span: error.span.map(f),
+        kind,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use guano_common::rowan::{ast::AstNode, TextRange, TextSize};
+    use guano_syntax::{nodes::SourceFile, SyntaxNode};
+
+    use super::{Parse, TextEdit};
+
+    const SOURCE: &str = r#"
+fun first(a: int) -> int {
+    let b = a * 2;
+    b + 1
+}
+
+class Point {
+    x: float;
+}
+
+fun second() {
+    print("unchanged");
+}
+"#;
+
+    fn offset(source: &str, pattern: &str) -> TextSize {
+        TextSize::from(source.find(pattern).unwrap() as u32)
+    }
+
+    /// Reparse, and make sure that the result matches a full parse.
+    fn reparse(parse: &Parse, edit: TextEdit) -> Parse {
+        let reparsed = parse.reparse(&edit);
+
+        let mut text = parse.text();
+        edit.apply(&mut text);
+        let full = Parse::new(&text);
+
+        assert_eq!(reparsed.text(), text);
+        assert_eq!(
+            format!("{:#?}", reparsed.file().syntax()),
+            format!("{:#?}", full.file().syntax())
+        );
+
+        let spans = |parse: &Parse| {
+            parse
+                .errors()
+                .iter()
+                .map(|e| (e.span, e.to_string()))
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(spans(&reparsed), spans(&full));
+        assert_eq!(reparsed.lex_errors(), full.lex_errors());
+
+        reparsed
+    }
+
+    /// Was the last item of the file reused, rather than parsed again?
+    fn reused_last_item(old: &Parse, new: &Parse) -> bool {
+        let last = |file: &SourceFile| -> SyntaxNode { file.syntax().last_child().unwrap() };
+
+        std::ptr::eq(&*last(old.file()).green(), &*last(new.file()).green())
+    }
+
+    #[test]
+    fn test_reparse_token() {
+        let parse = Parse::new(SOURCE);
+        let edit = TextEdit::insert(offset(SOURCE, "b + 1") + TextSize::from(1), "ee");
+        let reparsed = reparse(&parse, edit);
+
+        assert!(reparsed.text().contains("bee + 1"));
+        assert!(reused_last_item(&parse, &reparsed));
+
+        // Turning an identifier into a keyword changes the tree around it.
+        let edit = TextEdit::replace(
+            TextRange::at(offset(SOURCE, "b + 1"), TextSize::from(1)),
+            "nil",
+        );
+        reparse(&parse, edit);
+
+        // The error about the token is kept, even though only the token changed.
+        let source = "fun f() {\n    let a = 1 x;\n}\n";
+        let parse = Parse::new(source);
+        let edit = TextEdit::replace(TextRange::at(offset(source, "x"), TextSize::from(1)), "y");
+        assert!(!reparse(&parse, edit).errors().is_empty());
+
+        let source = "fun f() {\n    for i in 0..10 by 2 {}\n}\n";
+        let parse = Parse::new(source);
+        let edit = TextEdit::replace(TextRange::at(offset(source, "by"), TextSize::from(2)), "bx");
+        assert!(!reparse(&parse, edit).errors().is_empty());
+    }
+
+    #[test]
+    fn test_reparse_block() {
+        let parse = Parse::new(SOURCE);
+        let edit = TextEdit::insert(offset(SOURCE, "    b + 1"), "    let c = b;\n");
+        let reparsed = reparse(&parse, edit);
+        assert!(reused_last_item(&parse, &reparsed));
+
+        let edit = TextEdit::insert(offset(SOURCE, "    x: float"), "    y: float;\n");
+        let reparsed = reparse(&parse, edit);
+        assert!(reused_last_item(&parse, &reparsed));
+
+        // Errors inside of the block are replaced, and the ones after it are moved.
+        let broken = reparse(&parse, TextEdit::insert(offset(SOURCE, "print"), "@@ ;"));
+        assert_eq!(broken.errors().len(), 1);
+
+        let edit = TextEdit::insert(offset(SOURCE, "    b + 1"), "    let c = ) b;\n");
+        let reparsed = reparse(&broken, edit);
+        assert_eq!(reparsed.errors().len(), 3);
+        assert!(reused_last_item(&broken, &reparsed));
+    }
+
+    #[test]
+    fn test_reparse_full() {
+        let parse = Parse::new(SOURCE);
+
+        // Unbalanced braces change where the block ends.
+        let edit = TextEdit::insert(offset(SOURCE, "    b + 1"), "    if b {\n");
+        let reparsed = reparse(&parse, edit);
+        assert!(!reused_last_item(&parse, &reparsed));
+
+        // Edits that cross items reparse everything.
+        let edit = TextEdit::delete(TextRange::new(
+            offset(SOURCE, "b + 1"),
+            offset(SOURCE, "x: float"),
+        ));
+        reparse(&parse, edit);
+
+        // As do unterminated strings.
+        reparse(&parse, TextEdit::insert(offset(SOURCE, "b + 1"), "\""));
+    }
+}
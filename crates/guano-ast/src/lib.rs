@@ -1,3 +1,12 @@
+/// Diagnostics shared by every compiler phase.
+pub mod diagnostic;
+/// Generating documentation from source files.
+pub mod docgen;
+/// Checking the parser's guarantees against generated input.
+#[cfg(any(test, feature = "fuzz"))]
+pub mod fuzz;
+/// Reparsing files after an edit.
+pub mod incremental;
 /// Guano parsing structures.
 pub mod parsing;
 
@@ -7,17 +16,17 @@ pub use guano_syntax;
 use guano_syntax::nodes::SourceFile;
 use parsing::{combinators::Combinators, parsers::source_file, ParseContext, Parser};
 
-pub fn parse_file<'source>(
-    source: &'source str,
-) -> (
-    ParseContext<'source>,
-    Result<SourceFile, parsing::error::Error<'source>>,
-) {
+/// Parse an entire file, recovering from any syntax errors.
+///
+/// The returned [SourceFile] always covers the entire input,
+/// and the errors are reported in [ParseContext::errors].
+pub fn parse_file<'source>(source: &'source str) -> (ParseContext<'source>, SourceFile) {
     let mut context = ParseContext::new(source);
-    let result = source_file
+    let file = source_file
         .ast()
         .map(|n| SourceFile::cast(n).unwrap())
-        .parse(&mut context);
+        .parse(&mut context)
+        .expect("Source files should always recover from errors");
 
-    (context, result)
+    (context, file)
 }
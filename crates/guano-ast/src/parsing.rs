@@ -4,13 +4,19 @@ pub use context::*;
 
 /// Generalized helpers for parsing.
 pub mod combinators;
-/// Types for displaying a syntax tree.
+/// Formatting a syntax tree back into source code.
 pub mod display;
 /// Parse errors.
 pub mod error;
+/// Turning source text into tokens.
+pub mod lexer;
 
 /// Actual parsers for the various
 /// portions of guano.
 pub mod parsers;
 
 pub(crate) mod regex_registry;
+
+/// Recording which parsers ran, for debugging the grammar.
+#[cfg(feature = "trace")]
+pub mod trace;
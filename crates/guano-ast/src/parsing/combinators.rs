@@ -3,6 +3,8 @@ mod ast;
 mod count;
 mod expect;
 mod map;
+mod memoized;
+mod named;
 mod not;
 mod optional;
 mod peek;
@@ -10,6 +12,7 @@ mod regex;
 mod repeated;
 mod spanned;
 mod tag;
+mod token;
 mod tuple;
 
 pub mod errors;
@@ -20,6 +23,8 @@ pub mod types {
     pub use super::count::Count;
     pub use super::expect::Expect;
     pub use super::map::Map;
+    pub use super::memoized::Memoized;
+    pub use super::named::Named;
     pub use super::not::Not;
     pub use super::optional::Optional;
     pub use super::peek::Peek;
@@ -27,6 +32,7 @@ pub mod types {
     pub use super::repeated::Repeated;
     pub use super::spanned::Spanned;
     pub use super::tag::Tag;
+    pub use super::token::Token;
     pub use super::tuple::Tuple;
 }
 
@@ -43,6 +49,8 @@ pub use self::ast::Ast;
 pub use self::count::count;
 pub use self::expect::{expect, expected};
 pub use self::map::map;
+pub use self::memoized::{memoized, MemoEntry, MemoKey};
+pub use self::named::named;
 pub use self::not::not;
 pub use self::optional::optional;
 pub use self::peek::peek;
@@ -50,6 +58,7 @@ pub use self::regex::regex;
 pub use self::repeated::{at_least, repeated};
 pub use self::spanned::spanned;
 pub use self::tag::tag;
+pub use self::token::token;
 pub use self::tuple::tuple;
 
 use super::error::Error;
@@ -75,6 +84,19 @@ pub trait Combinators<'source>: Parser<'source> {
         map(self, func)
     }
 
+    #[inline]
+    fn memoized(self) -> Memoized<Self>
+    where
+        Self: Parser<'source, Output = Child, Error = Error<'source>> + 'static,
+    {
+        memoized(self)
+    }
+
+    #[inline]
+    fn named(self, name: &'static str) -> Named<Self> {
+        named(self, name)
+    }
+
     #[inline]
     fn ast(self) -> Ast<Self>
     where
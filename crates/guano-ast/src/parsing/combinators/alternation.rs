@@ -1,6 +1,6 @@
 use impl_trait_for_tuples::impl_for_tuples;
 
-use crate::parsing::{ParseContext, Parser};
+use crate::parsing::{error::MergeError, ParseContext, Parser};
 
 #[derive(Debug, Clone, Copy)]
 pub struct Alternation<A> {
@@ -37,13 +37,14 @@ pub trait AlternationTrait<'source> {
 impl<'source, P> AlternationTrait<'source> for &[P]
 where
     P: Parser<'source> + Clone,
+    P::Error: MergeError,
 {
     type Output = P::Output;
     type Error = P::Error;
 
     fn alt_parse(self, context: &mut ParseContext<'source>) -> Result<Self::Output, Self::Error> {
         assert_ne!(self.len(), 0);
-        let mut error = None;
+        let mut error: Option<P::Error> = None;
 
         for parser in self {
             let mut temp_context = context.clone();
@@ -54,7 +55,12 @@ where
                     *context.errors_mut() = temp_context.into_errors();
                     return Ok(output);
                 }
-                Err(err) => error = Some(err),
+                Err(err) => {
+                    error = Some(match error {
+                        Some(error) => error.merge(err),
+                        None => err,
+                    })
+                }
             }
         }
 
@@ -65,6 +71,7 @@ where
 impl<'source, P, const N: usize> AlternationTrait<'source> for [P; N]
 where
     P: Parser<'source> + Clone,
+    P::Error: MergeError,
 {
     type Output = P::Output;
     type Error = P::Error;
@@ -75,20 +82,25 @@ where
     }
 }
 
-#[impl_for_tuples(1, 10)]
+#[impl_for_tuples(1, 12)]
 #[tuple_types_custom_trait_bound(Parser<'source, Output = O, Error = E>)]
-impl<'source, O, E> AlternationTrait<'source> for Tuple {
+impl<'source, O, E: MergeError> AlternationTrait<'source> for Tuple {
     type Error = E;
     type Output = O;
 
+    /// Try each parser in order. If all of them fail,
+    /// their errors are merged into a single error.
     fn alt_parse(self, context: &mut ParseContext<'source>) -> Result<Self::Output, Self::Error> {
         let mut temp_context;
-        let mut error;
+        let mut error: Option<E> = None;
         for_tuples!(
             #(
                 temp_context = context.clone();
                 match Tuple::parse(self.Tuple, &mut temp_context) {
-                    Err(err) => error = err,
+                    Err(err) => error = Some(match error {
+                        Some(error) => error.merge(err),
+                        None => err,
+                    }),
                     Ok(output) => {
                         *context.position_mut() = temp_context.position();
                         *context.errors_mut() = temp_context.into_errors();
@@ -98,6 +110,6 @@ impl<'source, O, E> AlternationTrait<'source> for Tuple {
             )*
         );
 
-        Err(error)
+        Err(error.unwrap())
     }
 }
@@ -1,8 +1,10 @@
 use std::borrow::Cow;
 
+use guano_syntax::SyntaxKind;
+
 pub use super::expect::ExpectError;
 
-#[derive(Debug, thiserror::Error)]
+#[derive(Debug, Clone, thiserror::Error)]
 pub enum CombinatorError<'source> {
     #[error("Failed to match the regular expression {0:?}")]
     Regex(&'static str),
@@ -14,8 +16,19 @@ pub enum CombinatorError<'source> {
     Not(Cow<'static, str>),
     #[error("Expected character {0:?}")]
     Char(char),
+    #[error("Expected token {0:?}")]
+    Token(SyntaxKind),
     #[error("Output of child parser was a token rather than a node")]
     Ast,
+    #[error("{}", display_expected(.0))]
+    Expected(Vec<Cow<'static, str>>),
+}
+
+fn display_expected(items: &[Cow<'static, str>]) -> String {
+    match items {
+        [item] => format!("Expected {item}"),
+        items => format!("Expected one of {}", items.join(", ")),
+    }
 }
 
 impl CombinatorError<'_> {
@@ -27,7 +40,9 @@ impl CombinatorError<'_> {
             Expect(e) => Expect(e.to_static()),
             Not(n) => Not(n),
             Char(c) => Char(c),
+            Token(kind) => Token(kind),
             Ast => Ast,
+            Expected(items) => Expected(items),
         }
     }
 }
@@ -49,6 +64,10 @@ impl<'source> CombinatorError<'source> {
         Self::Expect(error)
     }
 
+    pub fn expected(item: impl Into<Cow<'static, str>>) -> Self {
+        Self::Expected(vec![item.into()])
+    }
+
     pub fn not(parser_name: impl Into<Cow<'static, str>>) -> Self {
         Self::Not(parser_name.into())
     }
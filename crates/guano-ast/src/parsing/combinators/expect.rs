@@ -75,7 +75,7 @@ where
     }
 }
 
-#[derive(Debug, ::thiserror::Error)]
+#[derive(Debug, Clone, ::thiserror::Error)]
 pub enum ExpectError<'source> {
     #[error("{0}")]
     Str(Cow<'source, str>),
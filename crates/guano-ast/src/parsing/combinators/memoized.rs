@@ -0,0 +1,143 @@
+use std::{any::TypeId, borrow::Cow, rc::Rc};
+
+use guano_common::rowan::TextSize;
+use guano_syntax::Child;
+
+use crate::parsing::{error::Error, ParseContext, Parser};
+
+#[derive(Debug, Clone, Copy)]
+pub struct Memoized<P> {
+    parser: P,
+}
+
+/// Remember the result of a parser at every position it's tried at,
+/// so that backtracking never parses the same input with it twice.
+///
+/// Results are keyed by the parser's type, so only parsers without any
+/// state, like functions, can be memoized. Two values of the same type,
+/// like `Punctuation::PLUS` and `Punctuation::MINUS`, would share results.
+#[inline]
+pub fn memoized<'source, P>(parser: P) -> Memoized<P>
+where
+    P: Parser<'source, Output = Child, Error = Error<'source>> + 'static,
+{
+    const {
+        assert!(
+            size_of::<P>() == 0,
+            "only stateless parsers can be memoized"
+        )
+    };
+
+    Memoized { parser }
+}
+
+/// Identifies a memoized parser, by its type, at a position.
+pub type MemoKey = (TypeId, TextSize);
+
+/// The remembered result of a memoized parser.
+#[derive(Debug, Clone)]
+pub enum MemoEntry<'source> {
+    Success {
+        output: Child,
+        end: TextSize,
+        /// The errors reported while parsing, which are reported again.
+        errors: Vec<Rc<Error<'source>>>,
+    },
+    Failure(Error<'source>),
+}
+
+impl<'source, P> Parser<'source> for Memoized<P>
+where
+    P: Parser<'source, Output = Child, Error = Error<'source>> + 'static,
+{
+    type Output = Child;
+    type Error = Error<'source>;
+
+    fn parse(self, context: &mut ParseContext<'source>) -> Result<Self::Output, Self::Error> {
+        let key = (TypeId::of::<P>(), context.position());
+
+        match context.memo(key) {
+            Some(MemoEntry::Success {
+                output,
+                end,
+                errors,
+            }) => {
+                *context.position_mut() = end;
+                context.errors_mut().extend(errors);
+
+                return Ok(output);
+            }
+            Some(MemoEntry::Failure(error)) => return Err(error),
+            None => {}
+        }
+
+        let errors_before = context.errors().len();
+        let result = self.parser.parse(context);
+
+        let entry = match &result {
+            Ok(output) => match context.errors().get(errors_before..) {
+                Some(errors) => MemoEntry::Success {
+                    output: output.clone(),
+                    end: context.position(),
+                    errors: errors.to_vec(),
+                },
+                // Errors were removed, which can't be replayed.
+                None => return result,
+            },
+            Err(error) => MemoEntry::Failure(error.clone()),
+        };
+
+        context.memoize(key, entry);
+
+        result
+    }
+
+    #[inline]
+    fn name(&self) -> Cow<'static, str> {
+        self.parser.name()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    use guano_syntax::{consts::Punctuation, Child};
+
+    use crate::parsing::{
+        combinators::{alternation, Combinators},
+        error::Res,
+        parsers::symbols::identifier::iden,
+        ParseContext, Parser,
+    };
+
+    static CALLS: AtomicUsize = AtomicUsize::new(0);
+
+    fn counted<'source>(context: &mut ParseContext<'source>) -> Res<'source, Child> {
+        CALLS.fetch_add(1, Ordering::Relaxed);
+
+        iden.then(Punctuation::SEMICOLON.expected())
+            .map(|(iden, _)| iden)
+            .parse(context)
+    }
+
+    #[test]
+    fn test_memoized() {
+        let mut context = ParseContext::new("name");
+
+        let (_, plus) = alternation((
+            counted.memoized().then(Punctuation::PLUS),
+            counted.memoized().then(Punctuation::MINUS),
+            counted.memoized().then(Punctuation::PLUS.expected()),
+        ))
+        .parse(&mut context)
+        .unwrap();
+
+        assert_eq!(CALLS.load(Ordering::Relaxed), 1);
+        assert!(context.is_eof());
+        assert_eq!(plus.kind(), guano_syntax::SyntaxKind::ERROR.into());
+
+        // The missing `;` was reported by the first call, and the missing `+` by the last.
+        assert_eq!(context.errors().len(), 2, "{:?}", context.errors());
+    }
+}
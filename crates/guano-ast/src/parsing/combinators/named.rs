@@ -0,0 +1,46 @@
+use std::borrow::Cow;
+
+use crate::parsing::{error::Error, ParseContext, Parser};
+
+#[derive(Debug, Clone, Copy)]
+pub struct Named<P> {
+    parser: P,
+    name: &'static str,
+}
+
+/// Give a parser a name, which is what will be
+/// reported as expected if it fails immediately.
+#[inline]
+pub fn named<'source, P: Parser<'source>>(parser: P, name: &'static str) -> Named<P> {
+    Named { parser, name }
+}
+
+impl<'source, P> Parser<'source> for Named<P>
+where
+    P: Parser<'source, Error = Error<'source>>,
+{
+    type Output = P::Output;
+    type Error = Error<'source>;
+
+    fn parse(self, context: &mut ParseContext<'source>) -> Result<Self::Output, Self::Error> {
+        let start_pos = context.position();
+        let name = self.name();
+
+        context.trace_enter(|| name.clone());
+        let result = self.parser.parse(context);
+        context.trace_exit(result.is_ok());
+
+        result.map_err(|error| {
+            // Keep errors from further in, they are more specific.
+            match error.span {
+                Some(span) if span.start() > start_pos => error,
+                _ => Error::expected(context.span(), name),
+            }
+        })
+    }
+
+    #[inline]
+    fn name(&self) -> Cow<'static, str> {
+        self.name.into()
+    }
+}
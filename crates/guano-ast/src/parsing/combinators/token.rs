@@ -0,0 +1,41 @@
+use guano_syntax::SyntaxKind;
+
+use crate::parsing::{error::Error, ParseContext, Parser};
+
+use super::errors::CombinatorError;
+
+#[derive(Debug, Clone, Copy)]
+pub struct Token {
+    kind: SyntaxKind,
+}
+
+/// Parse a single token of the given kind, returning its text.
+#[inline]
+pub fn token(kind: SyntaxKind) -> Token {
+    Token { kind }
+}
+
+impl<'source> Parser<'source> for Token {
+    type Output = &'source str;
+    type Error = Error<'source>;
+
+    fn parse(self, context: &mut ParseContext<'source>) -> Result<Self::Output, Self::Error> {
+        context.trace_enter(|| self.name());
+
+        let result = match context.token() {
+            Some(token) if token.kind == self.kind => Ok(context.consume(token)),
+            _ => {
+                let kind = CombinatorError::Token(self.kind);
+                Err(Error::spanned(context.span(), kind))
+            }
+        };
+
+        context.trace_exit(result.is_ok());
+
+        result
+    }
+
+    fn name(&self) -> std::borrow::Cow<'static, str> {
+        format!("Token({:?})", self.kind).into()
+    }
+}
@@ -1,12 +1,22 @@
-use std::{borrow::Cow, rc::Rc};
+#[cfg(feature = "trace")]
+use std::cell::Ref;
+use std::{borrow::Cow, cell::RefCell, rc::Rc};
 
-use guano_common::rowan::{TextLen, TextRange, TextSize};
+use guano_common::{
+    ahash::AHashMap,
+    rowan::{TextLen, TextRange, TextSize},
+};
 use guano_syntax::{Child, SyntaxNode};
 
 use super::{
+    combinators::{MemoEntry, MemoKey},
     error::{Error, ErrorKind, Res},
+    lexer::{self, LexError, Lexed, Token},
 };
 
+#[cfg(feature = "trace")]
+use super::trace::{Outcome, Trace};
+
 #[derive(Debug, Clone, Default)]
 /// Contains the state of a parser.
 /// This is the structure that all parsers,
@@ -16,18 +26,31 @@ pub struct ParseContext<'source> {
     source: &'source str,
     position: TextSize,
     errors: Vec<Rc<Error<'source>>>,
+    tokens: Rc<[Token]>,
+    lex_errors: Rc<[LexError]>,
+    /// Shared between clones, since results only depend on the position.
+    memo: Rc<RefCell<AHashMap<MemoKey, MemoEntry<'source>>>>,
+    #[cfg(feature = "trace")]
+    trace: Rc<RefCell<Trace<'source>>>,
 }
 
 impl<'source> ParseContext<'source> {
     /// Create a new parser context from
-    /// the source string.
+    /// the source string, lexing it up front.
     /// TODO: Ensure that the string is indexable by Rowan.
     #[inline]
     pub fn new(source: &'source str) -> Self {
+        let Lexed { tokens, errors } = lexer::lex(source);
+
         Self {
             source,
             position: 0.into(),
             errors: vec![],
+            tokens: tokens.into(),
+            lex_errors: errors.into(),
+            memo: Default::default(),
+            #[cfg(feature = "trace")]
+            trace: Default::default(),
         }
     }
 
@@ -74,7 +97,9 @@ impl<'source> ParseContext<'source> {
     /// Return the span of the current position.
     #[inline]
     pub fn span(&self) -> TextRange {
-        TextRange::at(self.position(), 1.into())
+        // Empty at the end, so that it never points past the input.
+        let len = if self.is_eof() { 0 } else { 1 };
+        TextRange::at(self.position(), len.into())
     }
 
     /// Return the span of the entire source.
@@ -89,6 +114,87 @@ impl<'source> ParseContext<'source> {
         &self.source[(u32::from(self.position()) as usize)..]
     }
 
+    /// Every token of the source.
+    #[inline]
+    pub fn tokens(&self) -> &[Token] {
+        &self.tokens
+    }
+
+    /// The token starting at the current position, if there is one.
+    pub fn token(&self) -> Option<Token> {
+        match self
+            .tokens
+            .binary_search_by_key(&self.position, |t| t.range.start())
+        {
+            Ok(index) => Some(self.tokens[index]),
+            // Parsers can stop in the middle of a token, like
+            // when `>>` closes two generic argument lists.
+            Err(_) => lexer::lex_token(self.source, self.position),
+        }
+    }
+
+    /// Move past `token` and return its text.
+    #[inline]
+    pub fn consume(&mut self, token: Token) -> &'source str {
+        debug_assert_eq!(token.range.start(), self.position);
+        self.position = token.range.end();
+
+        &self.source[token.range]
+    }
+
+    /// The errors found while lexing, such as unterminated strings.
+    #[inline]
+    pub fn lex_errors(&self) -> &[LexError] {
+        &self.lex_errors
+    }
+
+    /// The remembered result of a memoized parser.
+    #[inline]
+    pub fn memo(&self, key: MemoKey) -> Option<MemoEntry<'source>> {
+        self.memo.borrow().get(&key).cloned()
+    }
+
+    /// Remember the result of a memoized parser.
+    #[inline]
+    pub fn memoize(&self, key: MemoKey, entry: MemoEntry<'source>) {
+        self.memo.borrow_mut().insert(key, entry);
+    }
+
+    /// The parsers that have run so far.
+    #[cfg(feature = "trace")]
+    #[inline]
+    pub fn trace(&self) -> Ref<'_, Trace<'source>> {
+        self.trace.borrow()
+    }
+
+    /// Record that a parser has started.
+    /// Does nothing without the `trace` feature.
+    #[inline]
+    #[allow(unused_variables)]
+    pub fn trace_enter(&self, name: impl FnOnce() -> Cow<'static, str>) {
+        #[cfg(feature = "trace")]
+        self.trace.borrow_mut().enter(name(), self.position);
+    }
+
+    /// Record that the last started parser has returned.
+    /// Does nothing without the `trace` feature.
+    #[inline]
+    #[allow(unused_variables)]
+    pub fn trace_exit(&self, success: bool) {
+        #[cfg(feature = "trace")]
+        {
+            let outcome = if success {
+                Outcome::Success
+            } else {
+                Outcome::Failure
+            };
+
+            self.trace
+                .borrow_mut()
+                .exit(self.source, self.position, outcome);
+        }
+    }
+
     /// Return the captured error list.
     #[inline]
     pub fn errors(&self) -> &[Rc<Error<'source>>] {
@@ -193,6 +299,10 @@ where
     type Error = E;
 
     fn parse(mut self, context: &mut ParseContext<'source>) -> Result<T, E> {
-        self(context)
+        context.trace_enter(|| self.name());
+        let result = self(context);
+        context.trace_exit(result.is_ok());
+
+        result
     }
 }
@@ -1 +1,651 @@
+use std::fmt;
 
+use guano_common::rowan::{ast::AstNode, NodeOrToken, TextRange, TextSize};
+use guano_syntax::{nodes::SourceFile, SyntaxElement, SyntaxKind, SyntaxNode, SyntaxToken};
+use pretty::{RcDoc, Render, RenderAnnotated};
+use thiserror::Error;
+
+use super::lexer;
+
+/// How formatted source code is laid out.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FormatConfig {
+    /// The number of spaces per level of indentation.
+    pub indent_width: usize,
+    /// The width that lists are broken over multiple lines to fit in.
+    pub line_width: usize,
+}
+
+impl Default for FormatConfig {
+    fn default() -> Self {
+        Self {
+            indent_width: 4,
+            line_width: 100,
+        }
+    }
+}
+
+impl FormatConfig {
+    #[inline]
+    pub fn with_indent_width(mut self, indent_width: usize) -> Self {
+        self.indent_width = indent_width;
+        self
+    }
+
+    #[inline]
+    pub fn with_line_width(mut self, line_width: usize) -> Self {
+        self.line_width = line_width;
+        self
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum FormatError {
+    #[error("Can't format source with {0} syntax errors")]
+    SyntaxErrors(usize),
+    /// The formatted source has different tokens than the input,
+    /// which is a bug in the formatter.
+    #[error("Formatting would have changed the meaning of the source")]
+    Changed,
+}
+
+/// Format a file, keeping its comments.
+///
+/// Files with syntax errors are left alone, since it's
+/// unclear what their formatted version should look like.
+pub fn format(source: &str, config: &FormatConfig) -> Result<String, FormatError> {
+    let (context, file) = crate::parse_file(source);
+    let errors = context.errors().len() + context.lex_errors().len();
+    if errors > 0 {
+        return Err(FormatError::SyntaxErrors(errors));
+    }
+
+    let formatted = format_file(&file, config);
+
+    // Only whitespace and the commas between list items should have changed.
+    let (context, _) = crate::parse_file(&formatted);
+    if !context.errors().is_empty() || significant(source) != significant(&formatted) {
+        return Err(FormatError::Changed);
+    }
+
+    Ok(formatted)
+}
+
+/// Whether a file is already formatted.
+pub fn check(source: &str, config: &FormatConfig) -> Result<bool, FormatError> {
+    Ok(format(source, config)? == source)
+}
+
+/// Format a syntax tree, which should be free of errors.
+pub fn format_file(file: &SourceFile, config: &FormatConfig) -> String {
+    let source = file.syntax().to_string();
+    let formatter = Formatter {
+        config,
+        source: &source,
+    };
+
+    let items = formatter.items(file.syntax().children_with_tokens());
+    let doc = match items {
+        Some(items) => items.append(RcDoc::hardline()),
+        None => RcDoc::nil(),
+    };
+
+    let mut out = TrimmedLines::default();
+    doc.render_raw(config.line_width, &mut out)
+        .expect("Writing to a string can't fail");
+
+    out.text
+}
+
+/// Renders a document without the spaces the printer puts at the end
+/// of a line, like the indentation of blank lines.
+///
+/// The printer writes each newline and each run of spaces on its own, while
+/// tokens are never only spaces, so the text of tokens like multi-line
+/// comments and strings is kept exactly as it is.
+#[derive(Default)]
+struct TrimmedLines {
+    text: String,
+    spaces: usize,
+}
+
+impl Render for TrimmedLines {
+    type Error = fmt::Error;
+
+    fn write_str(&mut self, s: &str) -> Result<usize, Self::Error> {
+        if !s.is_empty() && s.bytes().all(|b| b == b' ') {
+            self.spaces += s.len();
+        } else {
+            if s != "\n" {
+                self.text.push_str(&" ".repeat(self.spaces));
+            }
+
+            self.spaces = 0;
+            self.text.push_str(s);
+        }
+
+        Ok(s.len())
+    }
+
+    fn fail_doc(&self) -> Self::Error {
+        fmt::Error
+    }
+}
+
+impl RenderAnnotated<'_, ()> for TrimmedLines {
+    fn push_annotation(&mut self, _: &()) -> Result<(), Self::Error> {
+        Ok(())
+    }
+
+    fn pop_annotation(&mut self) -> Result<(), Self::Error> {
+        Ok(())
+    }
+}
+
+/// The tokens of a source that aren't whitespace or commas.
+fn significant(source: &str) -> Vec<(SyntaxKind, &str)> {
+    lexer::lex(source)
+        .tokens
+        .into_iter()
+        .filter(|t| !matches!(t.kind, SyntaxKind::WHITESPACE | SyntaxKind::COMMA))
+        .map(|t| (t.kind, source[t.range].trim_end()))
+        .collect()
+}
+
+/// Nodes whose children are laid out one per line between braces.
+const BRACED: &[SyntaxKind] = &[
+    SyntaxKind::BLOCK,
+    SyntaxKind::CLASS_BLOCK,
+    SyntaxKind::ENUM_BODY,
+    SyntaxKind::IMPL_BODY,
+    SyntaxKind::MATCH_ARMS,
+    SyntaxKind::MODULE_BODY,
+    SyntaxKind::PROTO_BODY,
+];
+
+/// Nodes with comma separated children between delimiters,
+/// which are put on separate lines if they don't fit on one.
+const LISTS: &[SyntaxKind] = &[
+    SyntaxKind::CALL_EXPR,
+    SyntaxKind::ENUM_VARIANT_FIELDS,
+    SyntaxKind::FUNC_PARAMS,
+    SyntaxKind::FUNCTION_TYPE_PARAMS,
+    SyntaxKind::GENERIC_ARGS,
+    SyntaxKind::GENERIC_PARAMS,
+    SyntaxKind::IMPORT_ITEMS,
+    SyntaxKind::LIST_EXPR,
+    SyntaxKind::LIST_PATTERN,
+];
+
+struct Formatter<'a> {
+    config: &'a FormatConfig,
+    source: &'a str,
+}
+
+impl<'a> Formatter<'a> {
+    fn node(&self, node: &SyntaxNode) -> RcDoc<'static> {
+        match node.kind() {
+            kind if BRACED.contains(&kind) => self.braced(node),
+            kind if LISTS.contains(&kind) => self.list(node),
+            // Nothing inside of a string can be moved around.
+            SyntaxKind::INTERPOLATED_STRING => RcDoc::text(node.to_string()),
+            _ => self.inline(node.children_with_tokens()),
+        }
+    }
+
+    fn element(&self, element: &SyntaxElement) -> RcDoc<'static> {
+        match element {
+            NodeOrToken::Node(node) => self.node(node),
+            NodeOrToken::Token(token) => RcDoc::text(token.text().trim_end().to_string()),
+        }
+    }
+
+    /// Lay out elements on one line, with a space between them where it's needed.
+    fn inline(&self, elements: impl Iterator<Item = SyntaxElement>) -> RcDoc<'static> {
+        let mut doc = RcDoc::nil();
+        let mut previous: Option<SyntaxToken> = None;
+
+        for element in elements.filter(|e| !is_whitespace(e)) {
+            let Some(first) = first_token(&element) else {
+                continue;
+            };
+
+            if let Some(previous) = &previous {
+                // Doc comments are on their own lines before what they document.
+                if is_line_comment(previous) || previous.kind() == SyntaxKind::DOC_COMMENT {
+                    doc = doc.append(RcDoc::hardline());
+                } else if needs_space(previous, &first) {
+                    doc = doc.append(RcDoc::space());
+                }
+            }
+
+            doc = doc.append(self.element(&element));
+            previous = last_token(&element);
+        }
+
+        doc
+    }
+
+    /// Lay out elements one per line, keeping up to one blank line between them.
+    /// Comments on the same line as an element stay there.
+    fn items(&self, elements: impl Iterator<Item = SyntaxElement>) -> Option<RcDoc<'static>> {
+        let mut doc: Option<RcDoc<'static>> = None;
+        let mut previous: Option<SyntaxToken> = None;
+
+        for element in elements.filter(|e| !is_whitespace(e)) {
+            let Some(first) = first_token(&element) else {
+                continue;
+            };
+
+            let element_doc = self.element(&element);
+
+            doc = Some(match (doc, &previous) {
+                (Some(doc), Some(previous)) => {
+                    let gap = TextRange::new(trimmed_end(previous), first.text_range().start());
+                    let newlines = self.source[gap].matches('\n').count();
+
+                    if newlines == 0 && first.kind().is_comment() {
+                        doc.append(RcDoc::space()).append(element_doc)
+                    } else if newlines > 1 {
+                        doc.append(RcDoc::hardline())
+                            .append(RcDoc::hardline())
+                            .append(element_doc)
+                    } else {
+                        doc.append(RcDoc::hardline()).append(element_doc)
+                    }
+                }
+                _ => element_doc,
+            });
+
+            previous = last_token(&element);
+        }
+
+        doc
+    }
+
+    /// `{`, each child on its own indented line, then `}`.
+    fn braced(&self, node: &SyntaxNode) -> RcDoc<'static> {
+        let (before, mut inner, after) = split_delimited(node, SyntaxKind::LEFT_CURLY);
+
+        // A comment on the same line as the `{` stays there.
+        let mut trailing = RcDoc::nil();
+        let mut line_comment = false;
+        let first = inner.iter().position(|e| !is_whitespace(e));
+        if let Some(first) = first {
+            let same_line = !inner[..first]
+                .iter()
+                .any(|e| e.as_token().is_some_and(|t| t.text().contains('\n')));
+
+            if same_line && inner[first].kind().is_comment() {
+                let comment = inner.remove(first);
+                line_comment = comment.as_token().is_some_and(is_line_comment);
+                trailing = RcDoc::space().append(self.element(&comment));
+            }
+        }
+
+        let body = match self.items(inner.into_iter()) {
+            Some(items) => RcDoc::hardline()
+                .append(items)
+                .nest(self.indent())
+                .append(RcDoc::hardline()),
+            None if line_comment => RcDoc::hardline(),
+            None => RcDoc::nil(),
+        };
+
+        self.inline(before.into_iter())
+            .append(trailing)
+            .append(body)
+            .append(self.inline(after.into_iter()))
+    }
+
+    /// Delimiters around comma separated items, which go on
+    /// separate lines if the list doesn't fit on one line.
+    fn list(&self, node: &SyntaxNode) -> RcDoc<'static> {
+        let open = node
+            .children_with_tokens()
+            .filter_map(|e| e.into_token())
+            .map(|t| t.kind())
+            .find(|k| {
+                matches!(
+                    k,
+                    SyntaxKind::LEFT_PAREN
+                        | SyntaxKind::LEFT_BRACK
+                        | SyntaxKind::LEFT_CURLY
+                        | SyntaxKind::LT
+                )
+            });
+        let Some(open) = open else {
+            return self.inline(node.children_with_tokens());
+        };
+
+        let (before, inner, after) = split_delimited(node, open);
+
+        let mut comments = false;
+        let mut entries = vec![];
+        for element in inner.into_iter().filter(|e| !is_whitespace(e)) {
+            match element {
+                NodeOrToken::Token(token) if token.kind() == SyntaxKind::COMMA => {}
+                NodeOrToken::Token(token) if token.kind().is_comment() => {
+                    comments = true;
+                    entries.push((RcDoc::text(token.text().trim_end().to_string()), false));
+                }
+                NodeOrToken::Token(token) => entries.push((RcDoc::text(token.to_string()), true)),
+                NodeOrToken::Node(item) => {
+                    // The comma before the item is put back in below.
+                    let mut children = item.children_with_tokens().peekable();
+                    while children
+                        .next_if(|e| is_whitespace(e) || e.kind() == SyntaxKind::COMMA)
+                        .is_some()
+                    {}
+
+                    let has_tokens = children.clone().any(|e| first_token(&e).is_some());
+                    if has_tokens {
+                        entries.push((self.inline(children), true));
+                    }
+                }
+            }
+        }
+
+        let last_item = entries.iter().rposition(|(_, is_item)| *is_item);
+        let entries = entries.into_iter().enumerate().map(|(i, (doc, is_item))| {
+            if is_item && Some(i) != last_item {
+                doc.append(RcDoc::text(","))
+            } else {
+                doc
+            }
+        });
+
+        let body = if comments {
+            // Line comments can't share a line with anything after them.
+            RcDoc::hardline()
+                .append(RcDoc::intersperse(entries, RcDoc::hardline()))
+                .nest(self.indent())
+                .append(RcDoc::hardline())
+        } else if last_item.is_some() {
+            RcDoc::line_()
+                .append(RcDoc::intersperse(entries, RcDoc::line()))
+                .nest(self.indent())
+                .append(RcDoc::line_())
+                .group()
+        } else {
+            RcDoc::nil()
+        };
+
+        self.inline(before.into_iter())
+            .append(body)
+            .append(self.inline(after.into_iter()))
+    }
+
+    #[inline]
+    fn indent(&self) -> isize {
+        self.config.indent_width as isize
+    }
+}
+
+/// Split the children of a node into everything up to and including the
+/// first `open` token, everything before the last token, and the rest.
+fn split_delimited(
+    node: &SyntaxNode,
+    open: SyntaxKind,
+) -> (Vec<SyntaxElement>, Vec<SyntaxElement>, Vec<SyntaxElement>) {
+    let mut children = node.children_with_tokens().collect::<Vec<_>>();
+
+    let Some(start) = children.iter().position(|e| e.kind() == open) else {
+        return (children, vec![], vec![]);
+    };
+    let end = children
+        .iter()
+        .rposition(|e| !is_whitespace(e))
+        .filter(|&end| end > start && children[end].as_token().is_some())
+        .unwrap_or(children.len());
+
+    let after = children.split_off(end);
+    let inner = children.split_off(start + 1);
+
+    (children, inner, after)
+}
+
+fn is_whitespace(element: &SyntaxElement) -> bool {
+    element.kind() == SyntaxKind::WHITESPACE
+}
+
+fn is_line_comment(token: &SyntaxToken) -> bool {
+    token.kind().is_comment() && token.text().starts_with("//")
+}
+
+/// The end of a token, without the newline ending a line comment.
+fn trimmed_end(token: &SyntaxToken) -> TextSize {
+    token.text_range().start() + TextSize::of(token.text().trim_end())
+}
+
+fn first_token(element: &SyntaxElement) -> Option<SyntaxToken> {
+    match element {
+        NodeOrToken::Token(token) => Some(token.clone()),
+        NodeOrToken::Node(node) => node
+            .descendants_with_tokens()
+            .filter_map(|e| e.into_token())
+            .find(|t| t.kind() != SyntaxKind::WHITESPACE),
+    }
+}
+
+fn last_token(element: &SyntaxElement) -> Option<SyntaxToken> {
+    match element {
+        NodeOrToken::Token(token) => Some(token.clone()),
+        NodeOrToken::Node(node) => {
+            let mut token = node.last_token();
+            while let Some(t) = token {
+                if t.kind() != SyntaxKind::WHITESPACE {
+                    return Some(t);
+                }
+
+                // Don't leave the node.
+                token = t
+                    .prev_token()
+                    .filter(|t| node.text_range().contains_range(t.text_range()));
+            }
+
+            None
+        }
+    }
+}
+
+/// Whether two adjacent tokens should have a space between them.
+pub(crate) fn needs_space(previous: &SyntaxToken, next: &SyntaxToken) -> bool {
+    use SyntaxKind::*;
+
+    let previous_parent = previous.parent().map(|p| p.kind());
+    let next_parent = next.parent().map(|p| p.kind());
+
+    if previous.kind().is_comment() || next.kind().is_comment() {
+        return true;
+    }
+
+    if matches!(previous.kind(), LEFT_PAREN | LEFT_BRACK | DOT | COLON2)
+        || matches!(
+            next.kind(),
+            RIGHT_PAREN | RIGHT_BRACK | COMMA | SEMICOLON | DOT | COLON | COLON2 | QUES
+        )
+    {
+        return false;
+    }
+
+    // `-a`, `!a`, `0..10` and `-1` in patterns.
+    if matches!(previous_parent, Some(UNARY_OP | RANGE_OP))
+        || next_parent == Some(RANGE_OP)
+        || previous.kind() == MINUS && previous_parent == Some(LITERAL_PATTERN)
+    {
+        return false;
+    }
+
+    let generic = |parent| matches!(parent, Some(GENERIC_ARGS | GENERIC_PARAMS));
+
+    match next.kind() {
+        LEFT_PAREN => !matches!(
+            next_parent,
+            Some(CALL_EXPR | FUNC_PARAMS | ENUM_VARIANT_FIELDS | FUNCTION_TYPE_PARAMS)
+        ),
+        LEFT_BRACK => next_parent != Some(INDEX_EXPR),
+        LT | GT if generic(next_parent) => false,
+        _ => !(previous.kind() == LT && generic(previous_parent)),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{check, format, FormatConfig, FormatError};
+    use crate::fuzz::Generator;
+
+    #[track_caller]
+    fn assert_formats(source: &str, expected: &str) {
+        let config = FormatConfig::default();
+        let formatted = format(source, &config).unwrap();
+
+        assert_eq!(formatted, expected);
+        assert_eq!(format(&formatted, &config).unwrap(), formatted);
+    }
+
+    #[test]
+    fn test_format() {
+        let source = r#"
+import   math;
+
+
+pub class Point:Base{pub x:float;
+    pub y : float ;
+}
+fun main(a:int,b : List < int > )->int{
+let x=-a+b [0]*math::sqrt( 2.0 ) ;
+  if x>0..10{return x as int?;}else{}
+    match x{1=>"one",_=>"other"}
+}
+"#;
+
+        let expected = r#"import math;
+
+pub class Point: Base {
+    pub x: float;
+    pub y: float;
+}
+fun main(a: int, b: List<int>) -> int {
+    let x = -a + b[0] * math::sqrt(2.0);
+    if x > 0..10 {
+        return x as int?;
+    } else {}
+    match x {
+        1 => "one",
+        _ => "other"
+    }
+}
+"#;
+
+        assert_formats(source, expected);
+    }
+
+    #[test]
+    fn test_comments() {
+        let source = r#"
+// The entry point.
+fun main { // trailing
+    /* before */ let a = "a {b}";   // after
+/// doc
+    call(a, /* inline */ b);
+    call(a, // first
+        b);
+    /* end */ }
+"#;
+
+        let expected = r#"// The entry point.
+fun main { // trailing
+    /* before */
+    let a = "a {b}"; // after
+    /// doc
+    call(a, /* inline */ b);
+    call(
+        a,
+        // first
+        b
+    );
+    /* end */
+}
+"#;
+
+        assert_formats(source, expected);
+    }
+
+    #[test]
+    fn test_multi_line_tokens() {
+        // The text of a token is kept as is, even its trailing spaces and `\r\n`s,
+        // while the line breaks between tokens become `\n`s.
+        let source = "fun main {\r\n    /* a   \r\n     b */\r\n\r\n    call();\r\n}\r\n";
+        let expected = "fun main {\n    /* a   \r\n     b */\n\n    call();\n}\n";
+
+        assert_formats(source, expected);
+        assert_eq!(check(expected, &FormatConfig::default()), Ok(true));
+    }
+
+    #[test]
+    fn test_config() {
+        let source = "fun f { call(first_argument, second_argument, third_argument); }";
+
+        let narrow = FormatConfig::default().with_line_width(40);
+        let expected = "\
+fun f {
+    call(
+        first_argument,
+        second_argument,
+        third_argument
+    );
+}
+";
+        assert_eq!(format(source, &narrow).unwrap(), expected);
+
+        let tabs = FormatConfig::default().with_indent_width(2);
+        let expected = "\
+fun f {
+  call(first_argument, second_argument, third_argument);
+}
+";
+        assert_eq!(format(source, &tabs).unwrap(), expected);
+    }
+
+    #[test]
+    fn test_check() {
+        let config = FormatConfig::default();
+
+        assert_eq!(check("let a = 1;\n", &config), Ok(true));
+        assert_eq!(check("let a=1;\n", &config), Ok(false));
+        assert_eq!(check("", &config), Ok(true));
+        assert!(matches!(
+            check("let a = ;", &config),
+            Err(FormatError::SyntaxErrors(_))
+        ));
+    }
+
+    #[test]
+    fn test_idempotent() {
+        let config = FormatConfig::default();
+        let main = include_str!("../../../../main.guano");
+
+        let formatted = format(main, &config).unwrap();
+        assert_eq!(check(&formatted, &config), Ok(true));
+
+        let mut formatted_files = 0;
+        for seed in 0..200 {
+            let source = Generator::new(seed).file();
+
+            match format(&source, &config) {
+                Ok(formatted) => {
+                    assert_eq!(check(&formatted, &config), Ok(true), "{source:?}");
+                    formatted_files += 1;
+                }
+                Err(FormatError::SyntaxErrors(_)) => {}
+                Err(error) => panic!("{error} for {source:?}"),
+            }
+        }
+
+        assert!(
+            formatted_files > 20,
+            "Only {formatted_files} files were formatted"
+        );
+    }
+}
@@ -1,4 +1,4 @@
-use std::borrow::Cow;
+use std::{borrow::Cow, cmp::Ordering};
 
 use guano_common::rowan::TextRange;
 
@@ -6,7 +6,7 @@ use super::combinators::errors::CombinatorError;
 
 pub type Res<'source, O = &'source str> = Result<O, Error<'source>>;
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct Error<'source> {
     pub span: Option<TextRange>,
     pub kind: ErrorKind<'source>,
@@ -29,6 +29,52 @@ impl<'source> Error<'source> {
         }
     }
 
+    /// An error for a missing item, such as `'('` or `identifier`.
+    #[inline]
+    pub fn expected(span: TextRange, item: impl Into<Cow<'static, str>>) -> Self {
+        Self::spanned(span, CombinatorError::expected(item))
+    }
+
+    /// The items this error expected, if it was created by [Error::expected].
+    #[inline]
+    pub fn expected_items(&self) -> Option<&[Cow<'static, str>]> {
+        match &self.kind {
+            ErrorKind::Combinator(CombinatorError::Expected(items)) => Some(items),
+            _ => None,
+        }
+    }
+
+    /// Merge the errors of two failed alternatives.
+    ///
+    /// The error that got the furthest into the input wins. If both
+    /// stopped at the same position, their expected items are combined.
+    pub fn merge(self, other: Self) -> Self {
+        let start = |e: &Self| e.span.map(|s| s.start());
+
+        match start(&self).cmp(&start(&other)) {
+            Ordering::Greater => self,
+            Ordering::Less => other,
+            Ordering::Equal => match (self.expected_items(), other.expected_items()) {
+                (Some(items), Some(other_items)) => {
+                    let mut items = items.to_vec();
+
+                    for item in other_items {
+                        if !items.contains(item) {
+                            items.push(item.clone());
+                        }
+                    }
+
+                    Self {
+                        span: self.span,
+                        kind: CombinatorError::Expected(items).into(),
+                    }
+                }
+                (Some(_), None) => self,
+                _ => other,
+            },
+        }
+    }
+
     #[inline]
     pub fn to_static(self) -> Error<'static> {
         Error {
@@ -63,7 +109,7 @@ impl std::fmt::Display for Error<'_> {
     }
 }
 
-#[derive(Debug, ::thiserror::Error)]
+#[derive(Debug, Clone, ::thiserror::Error)]
 pub enum ErrorKind<'source> {
     #[error("{0}")]
     Combinator(CombinatorError<'source>),
@@ -75,6 +121,9 @@ pub enum ErrorKind<'source> {
     NeedChars(u32),
     #[error("{0}")]
     String(Cow<'static, str>),
+    /// Input that was skipped while recovering from a syntax error.
+    #[error("{0}")]
+    Skipped(Cow<'static, str>),
 }
 
 impl<'source> From<CombinatorError<'source>> for ErrorKind<'source> {
@@ -83,12 +132,25 @@ impl<'source> From<CombinatorError<'source>> for ErrorKind<'source> {
     }
 }
 
+/// Errors that can be merged when every branch of an alternation fails.
+pub trait MergeError {
+    fn merge(self, other: Self) -> Self;
+}
+
+impl MergeError for Error<'_> {
+    #[inline]
+    fn merge(self, other: Self) -> Self {
+        Error::merge(self, other)
+    }
+}
+
 impl ErrorKind<'_> {
     #[inline]
     pub fn to_static(self) -> ErrorKind<'static> {
         use ErrorKind::*;
         match self {
             String(s) => String(s),
+            Skipped(s) => Skipped(s),
             Combinator(c) => Combinator(c.to_static()),
             NeedBytes(b) => NeedBytes(b),
             NeedChars(c) => NeedChars(c),
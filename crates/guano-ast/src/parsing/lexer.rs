@@ -0,0 +1,457 @@
+use guano_common::rowan::{TextRange, TextSize};
+use guano_syntax::{consts::Punctuation, SyntaxKind};
+
+/// A single token of the source, trivia included.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Token {
+    pub kind: SyntaxKind,
+    pub range: TextRange,
+}
+
+/// An error found while lexing, separate from syntax errors.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LexError {
+    pub range: TextRange,
+    pub kind: LexErrorKind,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ::thiserror::Error)]
+pub enum LexErrorKind {
+    #[error("Unterminated string literal")]
+    UnterminatedString,
+    #[error("Unterminated character literal")]
+    UnterminatedChar,
+    #[error("Unterminated block comment")]
+    UnterminatedComment,
+}
+
+/// The tokens of an entire source, and the errors found while lexing it.
+#[derive(Debug, Clone, Default)]
+pub struct Lexed {
+    pub tokens: Vec<Token>,
+    pub errors: Vec<LexError>,
+}
+
+/// Lex an entire source in a single pass.
+///
+/// The tokens cover the source without gaps, so
+/// concatenating their text gives back the source.
+pub fn lex(source: &str) -> Lexed {
+    let mut lexer = Lexer::new(source);
+    let tokens = lexer.by_ref().collect();
+
+    Lexed {
+        tokens,
+        errors: lexer.errors,
+    }
+}
+
+/// Lex the single token starting at `offset`.
+///
+/// This doesn't know whether `offset` is inside of an interpolated
+/// string, so the token is always lexed as if it were outside of one.
+pub fn lex_token(source: &str, offset: TextSize) -> Option<Token> {
+    let mut lexer = Lexer::new(source);
+    lexer.position = offset.into();
+
+    lexer.next()
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Mode {
+    /// Inside of an interpolated string that started at the given offset.
+    String(usize),
+    /// Inside of an interpolation, nested in `depth` unclosed braces.
+    Interpolation { depth: usize },
+}
+
+/// Turns source text into [Token]s.
+#[derive(Debug, Clone)]
+pub struct Lexer<'source> {
+    source: &'source str,
+    position: usize,
+    modes: Vec<Mode>,
+    errors: Vec<LexError>,
+}
+
+impl<'source> Lexer<'source> {
+    pub fn new(source: &'source str) -> Self {
+        Self {
+            source,
+            position: 0,
+            modes: vec![],
+            errors: vec![],
+        }
+    }
+
+    /// The errors found so far.
+    #[inline]
+    pub fn errors(&self) -> &[LexError] {
+        &self.errors
+    }
+
+    fn report(&mut self, start: usize, kind: LexErrorKind) {
+        let range = TextRange::new(to_size(start), to_size(self.source.len()));
+        self.errors.push(LexError { range, kind });
+    }
+
+    /// The kind and length of the token at the start of `rest`,
+    /// outside of any string.
+    fn token(&mut self, rest: &str) -> (SyntaxKind, usize) {
+        let first = rest.chars().next().unwrap();
+
+        if first.is_whitespace() {
+            let len = rest
+                .find(|c: char| !c.is_whitespace())
+                .unwrap_or(rest.len());
+
+            (SyntaxKind::WHITESPACE, len)
+        } else if rest.starts_with("//") {
+            let len = rest.find('\n').map_or(rest.len(), |i| i + 1);
+
+            (comment_kind(&rest[..len]), len)
+        } else if let Some(body) = rest.strip_prefix("/*") {
+            let len = match body.find("*/") {
+                Some(i) => i + 4,
+                None => {
+                    self.report(self.position, LexErrorKind::UnterminatedComment);
+                    rest.len()
+                }
+            };
+
+            (comment_kind(&rest[..len]), len)
+        } else if first == '_' || first.is_ascii_alphabetic() {
+            let len = rest
+                .find(|c: char| c != '_' && !c.is_ascii_alphanumeric())
+                .unwrap_or(rest.len());
+            let kind = match &rest[..len] {
+                "_" => SyntaxKind::UNDERSCORE,
+                iden => SyntaxKind::from_keyword(iden).unwrap_or(SyntaxKind::IDEN),
+            };
+
+            (kind, len)
+        } else if first.is_ascii_digit() {
+            number(rest)
+        } else if first == '"' {
+            self.string(rest)
+        } else if first == '\'' {
+            self.char(rest)
+        } else if let Some(mark) = Punctuation::longest_match(rest) {
+            (mark.syntax_kind(), mark.as_str().len())
+        } else {
+            (SyntaxKind::ERROR, first.len_utf8())
+        }
+    }
+
+    /// Lex a string, which is either a whole `LIT_STRING`, or
+    /// the opening quote of an interpolated string.
+    fn string(&mut self, rest: &str) -> (SyntaxKind, usize) {
+        match skip_escaped(&rest[1..], |c| c == '"' || c == '{') {
+            Some(len) if rest[1 + len..].starts_with('"') => (SyntaxKind::LIT_STRING, len + 2),
+            Some(_) => {
+                self.modes.push(Mode::String(self.position));
+                (SyntaxKind::QUOTE, 1)
+            }
+            None => {
+                self.report(self.position, LexErrorKind::UnterminatedString);
+                (SyntaxKind::LIT_STRING, rest.len())
+            }
+        }
+    }
+
+    /// Lex the part of an interpolated string at the start of `rest`.
+    fn string_part(&mut self, rest: &str) -> (SyntaxKind, usize) {
+        if rest.starts_with('"') {
+            self.modes.pop();
+            (SyntaxKind::QUOTE, 1)
+        } else if rest.starts_with('{') {
+            self.modes.push(Mode::Interpolation { depth: 0 });
+            (SyntaxKind::LEFT_CURLY, 1)
+        } else {
+            let len = skip_escaped(rest, |c| c == '"' || c == '{').unwrap_or(rest.len());
+            (SyntaxKind::LIT_STRING_FRAGMENT, len)
+        }
+    }
+
+    /// Lex a char, which can't span multiple lines if it's unterminated.
+    fn char(&mut self, rest: &str) -> (SyntaxKind, usize) {
+        let line = rest[1..].split('\n').next().unwrap_or_default();
+
+        match skip_escaped(line, |c| c == '\'') {
+            Some(len) => (SyntaxKind::LIT_CHAR, len + 2),
+            None => {
+                let len = rest.find('\n').unwrap_or(rest.len());
+                let range = TextRange::at(to_size(self.position), to_size(len));
+
+                self.errors.push(LexError {
+                    range,
+                    kind: LexErrorKind::UnterminatedChar,
+                });
+
+                (SyntaxKind::LIT_CHAR, len)
+            }
+        }
+    }
+}
+
+impl Iterator for Lexer<'_> {
+    type Item = Token;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let source = self.source;
+        let rest = &source[self.position..];
+
+        if rest.is_empty() {
+            let unterminated = self.modes.iter().find_map(|mode| match mode {
+                Mode::String(start) => Some(*start),
+                Mode::Interpolation { .. } => None,
+            });
+
+            if let Some(start) = unterminated {
+                self.report(start, LexErrorKind::UnterminatedString);
+            }
+
+            self.modes.clear();
+            return None;
+        }
+
+        let (kind, len) = match self.modes.last().copied() {
+            Some(Mode::String(_)) => self.string_part(rest),
+            Some(Mode::Interpolation { depth }) => {
+                let index = self.modes.len() - 1;
+                let (kind, len) = self.token(rest);
+
+                // Braces only nest inside of the interpolation,
+                // the unmatched `}` goes back into the string.
+                match kind {
+                    SyntaxKind::LEFT_CURLY => {
+                        self.modes[index] = Mode::Interpolation { depth: depth + 1 };
+                    }
+                    SyntaxKind::RIGHT_CURLY if depth == 0 => {
+                        self.modes.pop();
+                    }
+                    SyntaxKind::RIGHT_CURLY => {
+                        self.modes[index] = Mode::Interpolation { depth: depth - 1 };
+                    }
+                    _ => {}
+                }
+
+                (kind, len)
+            }
+            None => self.token(rest),
+        };
+
+        let range = TextRange::at(to_size(self.position), to_size(len));
+        self.position += len;
+
+        Some(Token { kind, range })
+    }
+}
+
+/// Whether a comment is a doc comment. Like in Rust, `////`, `/***`
+/// and the empty `/**/` are regular comments.
+fn comment_kind(text: &str) -> SyntaxKind {
+    let is_outer = (text.starts_with("///") && !text.starts_with("////"))
+        || (text.starts_with("/**") && !text.starts_with("/***") && !text.starts_with("/**/"));
+
+    if is_outer {
+        SyntaxKind::DOC_COMMENT
+    } else if text.starts_with("//!") || text.starts_with("/*!") {
+        SyntaxKind::INNER_DOC_COMMENT
+    } else {
+        SyntaxKind::COMMENT
+    }
+}
+
+/// Lex a binary, hex, decimal or float number.
+fn number(rest: &str) -> (SyntaxKind, usize) {
+    let digits = |s: &str, is_digit: fn(&u8) -> bool| {
+        s.bytes().take_while(|b| *b == b'_' || is_digit(b)).count()
+    };
+    let bytes = rest.as_bytes();
+
+    match bytes {
+        [b'0', b'b' | b'B', b'0' | b'1', ..] => {
+            let len = 2 + digits(&rest[2..], |b| matches!(b, b'0' | b'1'));
+            (SyntaxKind::LIT_INTEGER, len)
+        }
+        [b'0', b'x' | b'X', b, ..] if b.is_ascii_hexdigit() => {
+            let len = 2 + digits(&rest[2..], u8::is_ascii_hexdigit);
+            (SyntaxKind::LIT_INTEGER, len)
+        }
+        _ => {
+            let len = digits(rest, u8::is_ascii_digit);
+
+            // `0..10` is a range, so the fraction needs a digit.
+            match &bytes[len..] {
+                [b'.', b, ..] if b.is_ascii_digit() => {
+                    let fraction = digits(&rest[len + 1..], u8::is_ascii_digit);
+                    (SyntaxKind::LIT_FLOAT, len + 1 + fraction)
+                }
+                _ => (SyntaxKind::LIT_INTEGER, len),
+            }
+        }
+    }
+}
+
+/// The length of `text` up to the first unescaped char matching `end`.
+fn skip_escaped(text: &str, end: impl Fn(char) -> bool) -> Option<usize> {
+    let mut chars = text.char_indices();
+
+    while let Some((i, ch)) = chars.next() {
+        match ch {
+            '\\' => {
+                chars.next();
+            }
+            ch if end(ch) => return Some(i),
+            _ => {}
+        }
+    }
+
+    None
+}
+
+#[inline]
+fn to_size(offset: usize) -> TextSize {
+    TextSize::from(offset as u32)
+}
+
+#[cfg(test)]
+mod test {
+    use guano_syntax::SyntaxKind::{self, *};
+
+    use super::{lex, LexErrorKind};
+
+    fn kinds(source: &str) -> Vec<(SyntaxKind, &str)> {
+        let lexed = lex(source);
+        assert!(lexed.errors.is_empty(), "{:?}", lexed.errors);
+
+        lexed
+            .tokens
+            .iter()
+            .map(|token| (token.kind, &source[token.range]))
+            .collect()
+    }
+
+    #[test]
+    fn test_tokens() {
+        assert_eq!(
+            kinds("let _x = 0b1_0 + 1.5; // done\n/* block */ _ 0..=10"),
+            [
+                (KW_LET, "let"),
+                (WHITESPACE, " "),
+                (IDEN, "_x"),
+                (WHITESPACE, " "),
+                (EQ, "="),
+                (WHITESPACE, " "),
+                (LIT_INTEGER, "0b1_0"),
+                (WHITESPACE, " "),
+                (PLUS, "+"),
+                (WHITESPACE, " "),
+                (LIT_FLOAT, "1.5"),
+                (SEMICOLON, ";"),
+                (WHITESPACE, " "),
+                (COMMENT, "// done\n"),
+                (COMMENT, "/* block */"),
+                (WHITESPACE, " "),
+                (UNDERSCORE, "_"),
+                (WHITESPACE, " "),
+                (LIT_INTEGER, "0"),
+                (DOT2_EQ, "..="),
+                (LIT_INTEGER, "10"),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_strings() {
+        assert_eq!(
+            kinds(r#"'\'' "a \{b\}" "x {y + "{z}"} {{}}""#),
+            [
+                (LIT_CHAR, r"'\''"),
+                (WHITESPACE, " "),
+                (LIT_STRING, r#""a \{b\}""#),
+                (WHITESPACE, " "),
+                (QUOTE, "\""),
+                (LIT_STRING_FRAGMENT, "x "),
+                (LEFT_CURLY, "{"),
+                (IDEN, "y"),
+                (WHITESPACE, " "),
+                (PLUS, "+"),
+                (WHITESPACE, " "),
+                (QUOTE, "\""),
+                (LEFT_CURLY, "{"),
+                (IDEN, "z"),
+                (RIGHT_CURLY, "}"),
+                (QUOTE, "\""),
+                (RIGHT_CURLY, "}"),
+                (LIT_STRING_FRAGMENT, " "),
+                (LEFT_CURLY, "{"),
+                (LEFT_CURLY, "{"),
+                (RIGHT_CURLY, "}"),
+                (RIGHT_CURLY, "}"),
+                (QUOTE, "\""),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_doc_comments() {
+        assert_eq!(
+            kinds("/// a\n//! b\n//// c\n/** d */ /*! e */ /*** f */ /**/"),
+            [
+                (DOC_COMMENT, "/// a\n"),
+                (INNER_DOC_COMMENT, "//! b\n"),
+                (COMMENT, "//// c\n"),
+                (DOC_COMMENT, "/** d */"),
+                (WHITESPACE, " "),
+                (INNER_DOC_COMMENT, "/*! e */"),
+                (WHITESPACE, " "),
+                (COMMENT, "/*** f */"),
+                (WHITESPACE, " "),
+                (COMMENT, "/**/"),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_errors() {
+        let errors = |source| {
+            lex(source)
+                .errors
+                .into_iter()
+                .map(|e| (e.kind, u32::from(e.range.start())))
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(
+            errors("a /* never closed"),
+            [(LexErrorKind::UnterminatedComment, 2)]
+        );
+        assert_eq!(
+            errors("x = \"never closed"),
+            [(LexErrorKind::UnterminatedString, 4)]
+        );
+        assert_eq!(
+            errors("x = \"{a} never closed"),
+            [(LexErrorKind::UnterminatedString, 4)]
+        );
+        assert_eq!(errors("'a\nb"), [(LexErrorKind::UnterminatedChar, 0)]);
+
+        // An unterminated char stops at the end of its line instead of
+        // running on to the next quote.
+        let source = "let c = 'a;\nlet d = 'b';";
+        assert_eq!(errors(source), [(LexErrorKind::UnterminatedChar, 8)]);
+        let tokens = lex(source).tokens;
+        let texts = tokens.iter().map(|t| &source[t.range]).collect::<Vec<_>>();
+        assert_eq!(
+            texts[6..],
+            ["'a;", "\n", "let", " ", "d", " ", "=", " ", "'b'", ";"]
+        );
+
+        let source = "\"a {b\" c";
+        let tokens = lex(source).tokens;
+        let text = tokens.iter().map(|t| &source[t.range]).collect::<String>();
+        assert_eq!(text, source);
+    }
+}
@@ -1,8 +1,12 @@
 use guano_syntax::{node, Child, SyntaxKind};
 
-use self::{declaration::module::module_items, ignorable::IgnorableParser};
+use self::{
+    declaration::module::module_items,
+    ignorable::{comment::attach_docs, eat_ignorable, IgnorableParser},
+    recovery::recover_item,
+};
 
-use super::{error::Res, ParseContext, Parser};
+use super::{combinators::tuple, error::Res, ParseContext, Parser};
 
 /// A declaration is anything that brings a new
 /// named item into scope.
@@ -16,9 +20,13 @@ pub mod ignorable;
 pub mod implementation;
 /// Syntax punctuation
 pub mod punctuation;
+/// Resynchronizing after syntax errors.
+pub mod recovery;
 /// Named items or keywords
 pub mod symbols;
 
+/// Parse an entire file. This never fails, anything that
+/// can't be parsed is wrapped in an `ERROR` node instead.
 pub fn source_file<'source>(context: &mut ParseContext<'source>) -> Res<'source, Child> {
     let (l_ws, items, r_ws) = module_items.padded().parse(context)?;
 
@@ -26,5 +34,15 @@ pub fn source_file<'source>(context: &mut ParseContext<'source>) -> Res<'source,
     children.extend(items);
     children.extend(r_ws);
 
-    Ok(node(SyntaxKind::SOURCE_FILE, children))
+    // Module items stop at an unmatched `}`, which doesn't close anything here.
+    while !context.is_eof() {
+        let (error, ws, items) =
+            tuple((recover_item, eat_ignorable, module_items)).parse(context)?;
+
+        children.push(error);
+        children.extend(ws);
+        children.extend(items);
+    }
+
+    Ok(node(SyntaxKind::SOURCE_FILE, attach_docs(children)))
 }
@@ -1,8 +1,13 @@
 use guano_syntax::Child;
 
-use crate::parsing::{combinators::alternation, error::Res, ParseContext, Parser};
+use crate::parsing::{
+    combinators::{alternation, Combinators},
+    error::Res,
+    ParseContext, Parser,
+};
 
 pub mod class;
+pub mod enumeration;
 pub mod function;
 pub mod import;
 pub mod module;
@@ -10,12 +15,18 @@ pub mod prototype;
 pub mod variable;
 
 pub fn decl<'source>(context: &mut ParseContext<'source>) -> Res<'source, Child> {
+    any_decl.memoized().parse(context)
+}
+
+fn any_decl<'source>(context: &mut ParseContext<'source>) -> Res<'source, Child> {
     alternation((
         variable::var,
         function::func,
         class::class,
         import::import,
         prototype::proto,
+        module::module,
+        enumeration::enumeration,
     ))
     .parse(context)
 }
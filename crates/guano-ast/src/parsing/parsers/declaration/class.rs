@@ -7,8 +7,8 @@ use crate::parsing::{
     combinators::{alternation, tuple, Combinators},
     error::Res,
     parsers::{
-        ignorable::{eat_ignorable, IgnorableParser},
-        symbols::{identifier::iden, path::path, ty::ty},
+        ignorable::{comment::attach_docs, eat_ignorable, IgnorableParser},
+        symbols::{generic::generic_params, identifier::iden, path::type_path, ty::ty},
     },
     ParseContext, Parser,
 };
@@ -31,6 +31,15 @@ pub fn class<'source>(context: &mut ParseContext<'source>) -> Res<'source, Child
     children.extend(r_ws);
     children.push(name);
 
+    if let Some((ws, generics)) = eat_ignorable
+        .then(generic_params)
+        .optional()
+        .parse(context)?
+    {
+        children.extend(ws);
+        children.push(generics);
+    }
+
     let (l_ws, extends, r_ws, body) = tuple((
         eat_ignorable,
         class_extends.optional(),
@@ -51,7 +60,7 @@ pub fn class<'source>(context: &mut ParseContext<'source>) -> Res<'source, Child
 
 pub fn class_extends<'source>(context: &mut ParseContext<'source>) -> Res<'source, Child> {
     let (col, path) = Punctuation::COLON
-        .then(eat_ignorable.then(path).optional())
+        .then(eat_ignorable.then(type_path).optional())
         .parse(context)?;
     let mut children = vec![col];
 
@@ -90,7 +99,7 @@ pub fn class_block<'source>(context: &mut ParseContext<'source>) -> Res<'source,
     children.extend(r_ws);
     children.push(r_curly);
 
-    Ok(node(SyntaxKind::CLASS_BLOCK, children))
+    Ok(node(SyntaxKind::CLASS_BLOCK, attach_docs(children)))
 }
 
 pub fn class_field<'source>(context: &mut ParseContext<'source>) -> Res<'source, Child> {
@@ -0,0 +1,247 @@
+use guano_syntax::{
+    consts::{Keyword, Punctuation},
+    node, Child, SyntaxKind,
+};
+
+use crate::parsing::{
+    combinators::{tuple, Combinators},
+    error::Res,
+    parsers::{
+        ignorable::{comment::attach_docs, eat_ignorable, IgnorableParser},
+        symbols::{generic::generic_params, identifier::iden, ty::ty},
+    },
+    ParseContext, Parser,
+};
+
+pub fn enumeration<'source>(context: &mut ParseContext<'source>) -> Res<'source, Child> {
+    let (pub_kw, (l_ws, enum_kw, r_ws), name) = tuple((
+        Keyword::PUB.optional(),
+        Keyword::ENUM.padded(),
+        iden.expected(),
+    ))
+    .parse(context)?;
+
+    let mut children = vec![];
+
+    if let Some(kw) = pub_kw {
+        children.push(kw);
+    }
+    children.extend(l_ws);
+    children.push(enum_kw);
+    children.extend(r_ws);
+    children.push(name);
+
+    if let Some((ws, generics)) = eat_ignorable
+        .then(generic_params)
+        .optional()
+        .parse(context)?
+    {
+        children.extend(ws);
+        children.push(generics);
+    }
+
+    let (ws, body) = eat_ignorable.then(enum_body.expected()).parse(context)?;
+    children.extend(ws);
+    children.push(body);
+
+    Ok(node(SyntaxKind::ENUM, children))
+}
+
+pub fn enum_body<'source>(context: &mut ParseContext<'source>) -> Res<'source, Child> {
+    let (l_curly, variants, (l_ws, r_curly)) = tuple((
+        Punctuation::LEFT_CURLY,
+        eat_ignorable.then(enum_variant).repeated(),
+        eat_ignorable.then(Punctuation::RIGHT_CURLY.expected()),
+    ))
+    .parse(context)?;
+
+    let mut children = vec![l_curly];
+
+    for (ws, variant) in variants {
+        children.extend(ws);
+        children.push(variant);
+    }
+
+    children.extend(l_ws);
+    children.push(r_curly);
+
+    Ok(node(SyntaxKind::ENUM_BODY, attach_docs(children)))
+}
+
+pub fn enum_variant<'source>(context: &mut ParseContext<'source>) -> Res<'source, Child> {
+    let (name, fields) =
+        tuple((iden, eat_ignorable.then(enum_variant_fields).optional())).parse(context)?;
+
+    let mut children = vec![name];
+
+    if let Some((ws, fields)) = fields {
+        children.extend(ws);
+        children.push(fields);
+    }
+
+    if let Some((ws, com)) = eat_ignorable
+        .then(Punctuation::COMMA)
+        .optional()
+        .parse(context)?
+    {
+        children.extend(ws);
+        children.push(com);
+    } else {
+        // The last variant doesn't need a comma.
+        let is_last = eat_ignorable
+            .then(Punctuation::RIGHT_CURLY)
+            .peek()
+            .optional()
+            .parse(context)?
+            .is_some();
+
+        if !is_last {
+            let com = Punctuation::COMMA.expected().parse(context)?;
+            children.push(com);
+        }
+    }
+
+    Ok(node(SyntaxKind::ENUM_VARIANT, children))
+}
+
+pub fn enum_variant_fields<'source>(context: &mut ParseContext<'source>) -> Res<'source, Child> {
+    let field = tuple((
+        iden,
+        eat_ignorable,
+        Punctuation::COLON.expected(),
+        eat_ignorable,
+        ty.expected(),
+    ));
+    let other_fields = eat_ignorable.then(enum_variant_field).repeated();
+
+    let (l_paren, (l_ws, fields, r_ws), r_paren) = tuple((
+        Punctuation::LEFT_PAREN,
+        tuple((field, other_fields)).optional().padded(),
+        Punctuation::RIGHT_PAREN.expected(),
+    ))
+    .parse(context)?;
+
+    let mut children = vec![l_paren];
+    children.extend(l_ws);
+
+    if let Some(((name, l_ws, col, r_ws, ty), other_fields)) = fields {
+        let mut first_field = vec![name];
+        first_field.extend(l_ws);
+        first_field.push(col);
+        first_field.extend(r_ws);
+        first_field.push(ty);
+
+        children.push(node(SyntaxKind::ENUM_VARIANT_FIELD, first_field));
+
+        for (ws, field) in other_fields {
+            children.extend(ws);
+            children.push(field);
+        }
+    }
+
+    children.extend(r_ws);
+    children.push(r_paren);
+
+    Ok(node(SyntaxKind::ENUM_VARIANT_FIELDS, children))
+}
+
+pub fn enum_variant_field<'source>(context: &mut ParseContext<'source>) -> Res<'source, Child> {
+    let (com, l_ws, name, m_ws, col, r_ws, ty) = tuple((
+        Punctuation::COMMA,
+        eat_ignorable,
+        iden.expected(),
+        eat_ignorable,
+        Punctuation::COLON.expected(),
+        eat_ignorable,
+        ty.expected(),
+    ))
+    .parse(context)?;
+
+    let mut children = vec![com];
+    children.extend(l_ws);
+    children.push(name);
+    children.extend(m_ws);
+    children.push(col);
+    children.extend(r_ws);
+    children.push(ty);
+
+    Ok(node(SyntaxKind::ENUM_VARIANT_FIELD, children))
+}
+
+#[cfg(test)]
+mod test {
+    use guano_common::rowan::ast::AstNode;
+    use guano_syntax::nodes::{Decl, Expr, ModuleItem};
+
+    #[test]
+    fn test_enum() {
+        let source = r#"
+        pub enum Shape {
+            Circle(radius: float),
+            Rect(w: float, h: float),
+            Empty,
+        }
+
+        impl Shape {
+            fun circle(radius: float) -> Shape {
+                Shape::Circle(radius)
+            }
+        }"#;
+
+        let (context, file) = crate::parse_file(source);
+        assert!(context.errors().is_empty(), "{:?}", context.errors());
+        assert!(context.is_eof());
+
+        let items = file.items().collect::<Vec<_>>();
+        let (shape, imp) = match &items[..] {
+            [ModuleItem::Decl(Decl::Enum(shape)), ModuleItem::Impl(imp)] => (shape, imp),
+            other => panic!("Unexpected items {other:?}"),
+        };
+        assert!(shape.is_pub());
+        assert_eq!(shape.name().unwrap().as_str(), "Shape");
+
+        let variants = shape
+            .variants()
+            .map(|v| {
+                let name = v.name().unwrap().as_str().to_owned();
+                let fields = v
+                    .fields()
+                    .map(|f| {
+                        let name = f.name().unwrap();
+                        format!("{}: {}", name.as_str(), f.ty().unwrap().syntax())
+                    })
+                    .collect::<Vec<_>>();
+
+                (name, fields, v.is_unit())
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(
+            variants,
+            [
+                ("Circle".into(), vec!["radius: float".into()], false),
+                (
+                    "Rect".into(),
+                    vec!["w: float".into(), "h: float".into()],
+                    false
+                ),
+                ("Empty".into(), vec![], true),
+            ]
+        );
+
+        assert_eq!(imp.ty().unwrap().syntax().to_string(), "Shape");
+        let body = imp
+            .impl_body()
+            .unwrap()
+            .funcs()
+            .next()
+            .unwrap()
+            .block()
+            .unwrap();
+        match body.end_expr() {
+            Some(Expr::CallExpr(call)) => {
+                assert_eq!(call.expr().unwrap().syntax().to_string(), "Shape::Circle")
+            }
+            other => panic!("Expected variant construction, found {other:?}"),
+        }
+    }
+}
@@ -10,8 +10,8 @@ use crate::parsing::{
     error::Res,
     parsers::{
         expression::block::block,
-        ignorable::{eat_ignorable, IgnorableParser},
-        symbols::{identifier::iden, ty::ty},
+        ignorable::{comment::attach_docs, eat_ignorable, IgnorableParser},
+        symbols::{generic::generic_params, identifier::iden, ty::ty},
     },
     ParseContext, Parser,
 };
@@ -26,6 +26,15 @@ pub fn func<'source>(context: &mut ParseContext<'source>) -> Res<'source, Child>
     children.push(name);
     children.extend(r_ws);
 
+    if let Some((generics, ws)) = generic_params
+        .then(eat_ignorable)
+        .optional()
+        .parse(context)?
+    {
+        children.push(generics);
+        children.extend(ws);
+    }
+
     if let Some((params, ws)) = func_params.then(eat_ignorable).optional().parse(context)? {
         children.push(params);
         children.extend(ws);
@@ -178,7 +187,7 @@ pub fn funcs<'source>(
         children.extend(r_ws);
         children.push(r_paren);
 
-        Ok(node(kind, children))
+        Ok(node(kind, attach_docs(children)))
     }
 }
 
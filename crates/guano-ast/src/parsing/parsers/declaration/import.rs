@@ -4,31 +4,31 @@ use guano_syntax::{
 };
 
 use crate::parsing::{
-    combinators::{tuple, Combinators},
+    combinators::{alternation, tuple, Combinators},
     error::Res,
     parsers::{
-        ignorable::eat_ignorable,
+        ignorable::{eat_ignorable, IgnorableParser},
         symbols::{identifier::iden, path::path},
     },
     ParseContext, Parser,
 };
 
 pub fn import<'source>(context: &mut ParseContext<'source>) -> Res<'source, Child> {
-    let (kw, l_ws, path, r_ws) = tuple((
+    let (kw, l_ws, body, r_ws) = tuple((
         Keyword::IMPORT,
         eat_ignorable,
-        path.expected(),
+        import_body.expect("Expected import item or items"),
         eat_ignorable,
     ))
     .parse(context)?;
 
     let mut children = vec![kw];
     children.extend(l_ws);
-    children.push(path);
+    children.push(body);
     children.extend(r_ws);
 
-    if let Some((alias, ws)) = import_alias.then(eat_ignorable).optional().parse(context)? {
-        children.push(alias);
+    if let Some((from, ws)) = import_from.then(eat_ignorable).optional().parse(context)? {
+        children.push(from);
         children.extend(ws);
     }
 
@@ -38,6 +38,103 @@ pub fn import<'source>(context: &mut ParseContext<'source>) -> Res<'source, Chil
     Ok(node(SyntaxKind::IMPORT, children))
 }
 
+pub fn import_body<'source>(context: &mut ParseContext<'source>) -> Res<'source, Child> {
+    alternation((import_items, first_import_item)).parse(context)
+}
+
+pub fn import_items<'source>(context: &mut ParseContext<'source>) -> Res<'source, Child> {
+    let other_items = eat_ignorable.then(import_item).repeated();
+    let (l_curly, (l_ws, items, r_ws), r_curly) = tuple((
+        Punctuation::LEFT_CURLY,
+        tuple((first_import_item, other_items)).optional().padded(),
+        Punctuation::RIGHT_CURLY.expected(),
+    ))
+    .parse(context)?;
+
+    let mut children = vec![l_curly];
+    children.extend(l_ws);
+
+    if let Some((first_item, other_items)) = items {
+        children.push(first_item);
+
+        for (ws, item) in other_items {
+            children.extend(ws);
+            children.push(item);
+        }
+    }
+
+    children.extend(r_ws);
+    children.push(r_curly);
+
+    Ok(node(SyntaxKind::IMPORT_ITEMS, children))
+}
+
+/// An import item that is not preceded by a comma.
+pub fn first_import_item<'source>(context: &mut ParseContext<'source>) -> Res<'source, Child> {
+    let (path, alias) =
+        tuple((import_path, eat_ignorable.then(import_alias).optional())).parse(context)?;
+
+    let mut children = vec![path];
+
+    if let Some((ws, alias)) = alias {
+        children.extend(ws);
+        children.push(alias);
+    }
+
+    Ok(node(SyntaxKind::IMPORT_ITEM, children))
+}
+
+pub fn import_item<'source>(context: &mut ParseContext<'source>) -> Res<'source, Child> {
+    let (com, ws, path, alias) = tuple((
+        Punctuation::COMMA,
+        eat_ignorable,
+        import_path.expect("Expected import path"),
+        eat_ignorable.then(import_alias).optional(),
+    ))
+    .parse(context)?;
+
+    let mut children = vec![com];
+    children.extend(ws);
+    children.push(path);
+
+    if let Some((ws, alias)) = alias {
+        children.extend(ws);
+        children.push(alias);
+    }
+
+    Ok(node(SyntaxKind::IMPORT_ITEM, children))
+}
+
+/// Parses `path`, `path::*` or `*`.
+pub fn import_path<'source>(context: &mut ParseContext<'source>) -> Res<'source, Child> {
+    if let Some(star) = Punctuation::STAR.optional().parse(context)? {
+        return Ok(node(SyntaxKind::IMPORT_PATH, vec![star]));
+    }
+
+    let (path, glob) = tuple((
+        path,
+        tuple((
+            eat_ignorable,
+            Punctuation::COLON2,
+            eat_ignorable,
+            Punctuation::STAR,
+        ))
+        .optional(),
+    ))
+    .parse(context)?;
+
+    let mut children = vec![path];
+
+    if let Some((l_ws, col, r_ws, star)) = glob {
+        children.extend(l_ws);
+        children.push(col);
+        children.extend(r_ws);
+        children.push(star);
+    }
+
+    Ok(node(SyntaxKind::IMPORT_PATH, children))
+}
+
 pub fn import_alias<'source>(context: &mut ParseContext<'source>) -> Res<'source, Child> {
     let (kw, ws, name) = tuple((Keyword::AS, eat_ignorable, iden.expected())).parse(context)?;
 
@@ -47,3 +144,73 @@ pub fn import_alias<'source>(context: &mut ParseContext<'source>) -> Res<'source
 
     Ok(node(SyntaxKind::IMPORT_ALIAS, children))
 }
+
+pub fn import_from<'source>(context: &mut ParseContext<'source>) -> Res<'source, Child> {
+    let (kw, ws, path) = tuple((Keyword::FROM, eat_ignorable, path.expected())).parse(context)?;
+
+    let mut children = vec![kw];
+    children.extend(ws);
+    children.push(path);
+
+    Ok(node(SyntaxKind::IMPORT_FROM, children))
+}
+
+#[cfg(test)]
+mod test {
+    use guano_common::rowan::ast::AstNode;
+    use guano_syntax::{nodes::Import, SyntaxNode};
+
+    use crate::parsing::{ParseContext, Parser};
+
+    fn parse_import(source: &str) -> Import {
+        let mut context = ParseContext::new(source);
+        let node = super::import.parse(&mut context).unwrap();
+        assert!(context.errors().is_empty(), "{:?}", context.errors());
+        assert!(context.is_eof());
+
+        Import::cast(SyntaxNode::new_root(node.into_node().unwrap())).unwrap()
+    }
+
+    fn entries(source: &str) -> Vec<(String, Option<String>, bool)> {
+        parse_import(source)
+            .entries()
+            .into_iter()
+            .map(|entry| {
+                let path = entry
+                    .path
+                    .iter()
+                    .map(|n| n.syntax().to_string())
+                    .collect::<Vec<_>>()
+                    .join("::");
+                let alias = entry.alias.map(|a| a.as_str().to_owned());
+
+                (path, alias, entry.is_glob)
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_import() {
+        assert_eq!(entries("import math;"), vec![("math".into(), None, false)]);
+        assert_eq!(
+            entries("import math::sqrt as srt;"),
+            vec![("math::sqrt".into(), Some("srt".into()), false)]
+        );
+        assert_eq!(
+            entries("import * from math;"),
+            vec![("math".into(), None, true)]
+        );
+        assert_eq!(
+            entries("import math::consts::*;"),
+            vec![("math::consts".into(), None, true)]
+        );
+        assert_eq!(
+            entries("import { sqrt as srt, consts::PI, * } from math;"),
+            vec![
+                ("math::sqrt".into(), Some("srt".into()), false),
+                ("math::consts::PI".into(), None, false),
+                ("math".into(), None, true),
+            ]
+        );
+    }
+}
@@ -7,8 +7,9 @@ use crate::parsing::{
     combinators::{alternation, tuple, Combinators},
     error::Res,
     parsers::{
-        ignorable::{eat_ignorable, IgnorableParser},
+        ignorable::{comment::attach_docs, eat_ignorable, IgnorableParser},
         implementation::implementation,
+        recovery::recover_item,
         symbols::identifier::iden,
     },
     ParseContext, Parser,
@@ -54,21 +55,92 @@ pub fn module_body<'source>(context: &mut ParseContext<'source>) -> Res<'source,
     children.extend(r_ws);
     children.push(r_curly);
 
-    Ok(node(SyntaxKind::MODULE_BODY, children))
+    Ok(node(SyntaxKind::MODULE_BODY, attach_docs(children)))
 }
 
+/// Parse items until the end of the input or an unmatched `}`,
+/// skipping over anything that isn't an item.
 pub fn module_items<'source>(context: &mut ParseContext<'source>) -> Res<'source, Vec<Child>> {
-    let items = module_item.then(eat_ignorable).repeated().parse(context)?;
-    let mut new_items = vec![];
+    let mut items = vec![];
 
-    for (item, ws) in items {
-        new_items.push(item);
-        new_items.extend(ws);
+    loop {
+        let item = module_item.then(eat_ignorable).optional().parse(context)?;
+
+        let (item, ws) = match item {
+            Some(item) => item,
+            None if context.is_eof() => break,
+            None if Punctuation::RIGHT_CURLY
+                .peek()
+                .optional()
+                .parse(context)?
+                .is_some() =>
+            {
+                break
+            }
+            None => recover_item.then(eat_ignorable).parse(context)?,
+        };
+
+        items.push(item);
+        items.extend(ws);
     }
 
-    Ok(new_items)
+    Ok(items)
 }
 
 pub fn module_item<'source>(context: &mut ParseContext<'source>) -> Res<'source, Child> {
     alternation((decl, implementation)).parse(context)
 }
+
+#[cfg(test)]
+mod test {
+    use guano_syntax::nodes::{Decl, ModuleItem};
+
+    #[test]
+    fn test_module() {
+        let source = r#"
+        pub module geometry {
+            pub class Point {
+                pub x: float;
+                pub y: float;
+            }
+
+            impl Point {
+                pub fun length -> float {
+                    return math::sqrt(this.x * this.x + this.y * this.y);
+                }
+            }
+
+            module shapes {
+                fun unit -> Point;
+            }
+        }
+
+        fun main {}"#;
+
+        let (context, file) = crate::parse_file(source);
+        assert!(context.errors().is_empty());
+        assert!(context.is_eof());
+
+        let items = file.items().collect::<Vec<_>>();
+        assert_eq!(items.len(), 2);
+
+        let geometry = match &items[0] {
+            ModuleItem::Decl(Decl::Module(module)) => module,
+            other => panic!("Expected module, found {other:?}"),
+        };
+        assert!(geometry.is_pub());
+        assert_eq!(geometry.name().unwrap().as_str(), "geometry");
+
+        let items = geometry.items().collect::<Vec<_>>();
+        assert_eq!(items.len(), 3);
+        assert!(matches!(items[0], ModuleItem::Decl(Decl::Class(_))));
+        assert!(matches!(items[1], ModuleItem::Impl(_)));
+
+        let shapes = match &items[2] {
+            ModuleItem::Decl(Decl::Module(module)) => module,
+            other => panic!("Expected module, found {other:?}"),
+        };
+        assert!(!shapes.is_pub());
+        assert_eq!(shapes.items().count(), 1);
+    }
+}
@@ -8,7 +8,7 @@ use crate::parsing::{
     error::Res,
     parsers::{
         ignorable::eat_ignorable,
-        symbols::{identifier::iden, path::path},
+        symbols::{generic::generic_params, identifier::iden, path::type_path},
     },
     ParseContext, Parser,
 };
@@ -36,12 +36,18 @@ pub fn proto<'source>(context: &mut ParseContext<'source>) -> Res<'source, Child
     children.push(name);
     children.extend(r_ws);
 
-    let (extends, body) = tuple((
+    let (generics, extends, body) = tuple((
+        generic_params.then(eat_ignorable).optional(),
         proto_extends.then(eat_ignorable).optional(),
         proto_body.expected(),
     ))
     .parse(context)?;
 
+    if let Some((generics, ws)) = generics {
+        children.push(generics);
+        children.extend(ws);
+    }
+
     if let Some((extends, ws)) = extends {
         children.push(extends);
         children.extend(ws);
@@ -53,7 +59,7 @@ pub fn proto<'source>(context: &mut ParseContext<'source>) -> Res<'source, Child
 
 pub fn proto_extends<'source>(context: &mut ParseContext<'source>) -> Res<'source, Child> {
     let extensions = tuple((
-        path.map(|p| node(SyntaxKind::PROTO_EXTENSION, vec![p])),
+        type_path.map(|p| node(SyntaxKind::PROTO_EXTENSION, vec![p])),
         eat_ignorable.then(proto_extension).repeated(),
     ));
 
@@ -78,7 +84,7 @@ pub fn proto_extends<'source>(context: &mut ParseContext<'source>) -> Res<'sourc
 
 pub fn proto_extension<'source>(context: &mut ParseContext<'source>) -> Res<'source, Child> {
     let (plus, ws, path) =
-        tuple((Punctuation::PLUS, eat_ignorable, path.expected())).parse(context)?;
+        tuple((Punctuation::PLUS, eat_ignorable, type_path.expected())).parse(context)?;
 
     let mut children = vec![plus];
     children.extend(ws);
@@ -1,8 +1,12 @@
 use guano_syntax::{nodes::Expr, Child};
 
-use crate::parsing::{combinators::alternation, error::Res, ParseContext, Parser};
+use crate::parsing::{
+    combinators::{alternation, Combinators},
+    error::Res,
+    ParseContext, Parser,
+};
 
-use self::block::{for_expr, if_expr, loop_expr, while_expr};
+use self::block::{for_expr, if_expr, loop_expr, match_expr, try_expr, while_expr};
 
 pub mod block;
 pub mod operator;
@@ -10,7 +14,20 @@ pub mod pratt;
 pub mod primary;
 
 pub fn expr<'source>(context: &mut ParseContext<'source>) -> Res<'source, Child> {
-    alternation((pratt::pratt, loop_expr, for_expr, while_expr, if_expr)).parse(context)
+    any_expr.memoized().parse(context)
+}
+
+fn any_expr<'source>(context: &mut ParseContext<'source>) -> Res<'source, Child> {
+    alternation((
+        pratt::pratt,
+        loop_expr,
+        for_expr,
+        while_expr,
+        if_expr,
+        try_expr,
+        match_expr,
+    ))
+    .parse(context)
 }
 
 pub trait ExprExt {
@@ -24,7 +41,9 @@ impl ExprExt for Expr {
             | Expr::IfExpr(_)
             | Expr::LoopExpr(_)
             | Expr::WhileExpr(_)
-            | Expr::ForExpr(_) => true,
+            | Expr::ForExpr(_)
+            | Expr::TryExpr(_)
+            | Expr::MatchExpr(_) => true,
             _ => false,
         }
     }
@@ -32,9 +51,14 @@ impl ExprExt for Expr {
 
 #[cfg(test)]
 mod tests {
+    use guano_common::rowan::TextRange;
     use guano_syntax::SyntaxNode;
 
-    use crate::parsing::{parsers::ignorable::IgnorableParser, ParseContext, Parser};
+    use crate::parsing::{
+        combinators::Combinators,
+        parsers::ignorable::{eat_ignorable, IgnorableParser},
+        ParseContext, Parser,
+    };
 
     #[test]
     fn test_binary() {
@@ -50,4 +74,33 @@ mod tests {
             Err(err) => println!("Error: {err}"),
         }
     }
+
+    #[test]
+    fn test_expected_one_of() {
+        let mut context = ParseContext::new("  ) + 1");
+        let error = eat_ignorable
+            .then(super::expr)
+            .parse(&mut context)
+            .unwrap_err();
+
+        assert_eq!(error.span, Some(TextRange::at(2.into(), 1.into())));
+
+        let items = error.expected_items().unwrap();
+        for item in [
+            "'{'",
+            "'('",
+            "'['",
+            "literal",
+            "identifier",
+            "'return'",
+            "'if'",
+        ] {
+            assert!(
+                items.contains(&item.into()),
+                "{item} is missing from {items:?}"
+            );
+        }
+
+        assert!(error.to_string().contains("Expected one of '{', '(', '['"));
+    }
 }
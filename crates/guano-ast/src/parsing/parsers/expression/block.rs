@@ -1,54 +1,74 @@
 mod r#for;
 mod r#if;
 mod r#loop;
+mod r#match;
+mod patterns;
 mod statement;
+mod r#try;
 mod r#while;
 
+pub use patterns::*;
 pub use r#for::*;
 pub use r#if::*;
 pub use r#loop::*;
+pub use r#match::*;
+pub use r#try::*;
 pub use r#while::*;
 pub use statement::*;
 
 use guano_syntax::{consts::Punctuation, node, Child, SyntaxKind};
 
 use crate::parsing::{
-    combinators::{tuple, Combinators},
+    combinators::{alternation, Combinators},
     error::Res,
-    parsers::{expression::expr, ignorable::IgnorableParser},
+    parsers::{
+        expression::expr,
+        ignorable::{comment::attach_docs, eat_ignorable},
+        recovery::recover_statement,
+    },
     ParseContext, Parser,
 };
 
 use statement::statement;
 
 /// Parse a block expression.
+///
+/// Anything that isn't a statement is skipped until the
+/// next statement boundary and wrapped in an `ERROR` node.
 pub fn block<'source>(context: &mut ParseContext<'source>) -> Res<'source, Child> {
-    let (l_curly, statements, end_expr, r_curly) = tuple((
-        Punctuation::LEFT_CURLY,
-        statement.padded().repeated(),
-        expr.optional().padded(),
-        Punctuation::RIGHT_CURLY.expected(),
-    ))
-    .parse(context)?;
+    let (l_curly, l_ws) = Punctuation::LEFT_CURLY.then(eat_ignorable).parse(context)?;
 
     let mut children = vec![l_curly];
-    for (l_ws, statement, r_ws) in statements {
-        children.extend(l_ws);
-        children.push(statement);
-        children.extend(r_ws);
-    }
-
-    let (l_ws, end_expr, r_ws) = end_expr;
     children.extend(l_ws);
-    if let Some(expr) = end_expr {
-        children.push(expr);
+
+    loop {
+        let item = alternation((statement, expr))
+            .then(eat_ignorable)
+            .optional()
+            .parse(context)?;
+
+        let (item, ws) = match item {
+            Some(item) => item,
+            None if context.is_eof() => break,
+            None if Punctuation::RIGHT_CURLY
+                .peek()
+                .optional()
+                .parse(context)?
+                .is_some() =>
+            {
+                break
+            }
+            None => recover_statement.then(eat_ignorable).parse(context)?,
+        };
+
+        children.push(item);
+        children.extend(ws);
     }
-    children.extend(r_ws);
-    children.push(r_curly);
 
-    let block = node(SyntaxKind::BLOCK, children);
+    let r_curly = Punctuation::RIGHT_CURLY.expected().parse(context)?;
+    children.push(r_curly);
 
-    Ok(block)
+    Ok(node(SyntaxKind::BLOCK, attach_docs(children)))
 }
 
 #[cfg(test)]
@@ -128,4 +148,19 @@ mod test {
             }
         }
     }
+
+    #[test]
+    fn test_block_end_expr() {
+        let mut context = ParseContext::new("{ a; b }");
+        let node = super::block.parse(&mut context).unwrap();
+        let block = SyntaxNode::new_root(node.into_node().unwrap());
+
+        // `b` is left for the block instead of failing as a statement missing its `;`.
+        assert!(context.errors().is_empty(), "{:?}", context.errors());
+        let Some(Expr::Block(block)) = Expr::cast(block) else {
+            panic!("Somehow not a block");
+        };
+        assert_eq!(block.statements().count(), 1);
+        assert_eq!(block.expr().unwrap().syntax().to_string(), "b");
+    }
 }
@@ -3,11 +3,7 @@ use guano_syntax::{consts::Keyword, node, Child, SyntaxKind};
 use crate::parsing::{
     combinators::{tuple, Combinators},
     error::Res,
-    parsers::{
-        expression::expr,
-        ignorable::{eat_ignorable, IgnorableParser},
-        symbols::identifier::iden,
-    },
+    parsers::{expression::expr, ignorable::IgnorableParser, symbols::identifier::iden},
     ParseContext, Parser,
 };
 
@@ -27,10 +23,11 @@ pub fn for_expr<'source>(context: &mut ParseContext<'source>) -> Res<'source, Ch
     children.extend(r_ws);
     children.push(in_kw);
 
-    let (expr, ws, block) =
-        tuple((expr.expected(), eat_ignorable, block.expected())).parse(context)?;
+    let ((l_ws, expr, r_ws), block) =
+        tuple((expr.expected().padded(), block.expected())).parse(context)?;
+    children.extend(l_ws);
     children.push(expr);
-    children.extend(ws);
+    children.extend(r_ws);
     children.push(block);
 
     Ok(node(SyntaxKind::FOR_EXPR, children))
@@ -1,7 +1,7 @@
 use guano_syntax::{consts::Keyword, node, Child, SyntaxKind};
 
 use crate::parsing::{
-    combinators::{tuple, Combinators, alternation},
+    combinators::{alternation, tuple, Combinators},
     error::Res,
     parsers::{
         expression::expr,
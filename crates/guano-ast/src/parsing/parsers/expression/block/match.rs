@@ -0,0 +1,183 @@
+use guano_common::rowan::Language;
+use guano_syntax::{
+    consts::{Keyword, Punctuation},
+    node, Child, Lang, SyntaxKind,
+};
+
+use crate::parsing::{
+    combinators::{alternation, tuple, Combinators},
+    error::Res,
+    parsers::{
+        expression::expr,
+        ignorable::{eat_ignorable, IgnorableParser},
+    },
+    ParseContext, Parser,
+};
+
+use super::{block, for_expr, if_expr, loop_expr, pattern, try_expr, while_expr};
+
+pub fn match_expr<'source>(context: &mut ParseContext<'source>) -> Res<'source, Child> {
+    let (kw, (l_ws, scrutinee, r_ws), arms) = tuple((
+        Keyword::MATCH,
+        expr.expected().padded(),
+        match_arms.expected(),
+    ))
+    .parse(context)?;
+
+    let mut children = vec![kw];
+    children.extend(l_ws);
+    children.push(scrutinee);
+    children.extend(r_ws);
+    children.push(arms);
+
+    Ok(node(SyntaxKind::MATCH_EXPR, children))
+}
+
+pub fn match_arms<'source>(context: &mut ParseContext<'source>) -> Res<'source, Child> {
+    let (l_curly, arms, (l_ws, r_curly)) = tuple((
+        Punctuation::LEFT_CURLY,
+        eat_ignorable.then(match_arm).repeated(),
+        eat_ignorable.then(Punctuation::RIGHT_CURLY.expected()),
+    ))
+    .parse(context)?;
+
+    let mut children = vec![l_curly];
+
+    for (ws, arm) in arms {
+        children.extend(ws);
+        children.push(arm);
+    }
+
+    children.extend(l_ws);
+    children.push(r_curly);
+
+    Ok(node(SyntaxKind::MATCH_ARMS, children))
+}
+
+pub fn match_arm<'source>(context: &mut ParseContext<'source>) -> Res<'source, Child> {
+    let (pattern, (l_ws, arrow, r_ws), expr) = tuple((
+        pattern,
+        Punctuation::FAT_ARROW.expected().padded(),
+        match_arm_body.expect("Expected expression"),
+    ))
+    .parse(context)?;
+
+    let is_block = match expr.as_node() {
+        Some(node) => Lang::kind_from_raw(node.kind()).is_block_expr(),
+        None => false,
+    };
+
+    let mut children = vec![pattern];
+    children.extend(l_ws);
+    children.push(arrow);
+    children.extend(r_ws);
+    children.push(expr);
+
+    if let Some((ws, com)) = eat_ignorable
+        .then(Punctuation::COMMA)
+        .optional()
+        .parse(context)?
+    {
+        children.extend(ws);
+        children.push(com);
+    } else if !is_block {
+        // The last arm doesn't need a comma.
+        let is_last = eat_ignorable
+            .then(Punctuation::RIGHT_CURLY)
+            .peek()
+            .optional()
+            .parse(context)?
+            .is_some();
+
+        if !is_last {
+            let com = Punctuation::COMMA.expected().parse(context)?;
+            children.push(com);
+        }
+    }
+
+    Ok(node(SyntaxKind::MATCH_ARM, children))
+}
+
+/// Like in Rust, an arm body that starts with a block-like expression
+/// ends with it, so that `{ ... } is string => ...` isn't parsed as
+/// a type check on the block.
+fn match_arm_body<'source>(context: &mut ParseContext<'source>) -> Res<'source, Child> {
+    alternation((
+        block, if_expr, loop_expr, for_expr, while_expr, try_expr, match_expr, expr,
+    ))
+    .parse(context)
+}
+
+#[cfg(test)]
+mod test {
+    use guano_common::rowan::ast::AstNode;
+    use guano_syntax::{
+        nodes::{Expr, Pattern},
+        SyntaxNode,
+    };
+
+    use crate::parsing::{ParseContext, Parser};
+
+    #[test]
+    fn test_match() {
+        let source = r#"match value {
+            nil => "nothing",
+            -1 => "negative one",
+            n is int => { print(n); "int" }
+            is string => "string",
+            [first, _] => first,
+            _ => other
+        }"#;
+        let mut context = ParseContext::new(source);
+
+        let node = super::match_expr.parse(&mut context).unwrap();
+        assert!(context.errors().is_empty(), "{:?}", context.errors());
+        assert!(context.is_eof());
+
+        let match_expr = match Expr::cast(SyntaxNode::new_root(node.into_node().unwrap())) {
+            Some(Expr::MatchExpr(match_expr)) => match_expr,
+            other => panic!("Expected match expression, found {other:?}"),
+        };
+        assert_eq!(match_expr.expr().unwrap().syntax().to_string(), "value");
+
+        let patterns = match_expr
+            .arms()
+            .map(|arm| arm.pattern().unwrap())
+            .collect::<Vec<_>>();
+        assert_eq!(patterns.len(), 6);
+
+        assert!(matches!(patterns[0], Pattern::NilPattern(_)));
+        match &patterns[1] {
+            Pattern::LiteralPattern(literal) => assert!(literal.is_negative()),
+            other => panic!("Expected literal pattern, found {other:?}"),
+        }
+        match &patterns[2] {
+            Pattern::TypePattern(ty) => {
+                assert_eq!(ty.name().unwrap().as_str(), "n");
+                assert_eq!(ty.ty().unwrap().syntax().to_string(), "int");
+            }
+            other => panic!("Expected type pattern, found {other:?}"),
+        }
+        match &patterns[3] {
+            Pattern::TypePattern(ty) => assert!(ty.name().is_none()),
+            other => panic!("Expected type pattern, found {other:?}"),
+        }
+        match &patterns[4] {
+            Pattern::ListPattern(list) => {
+                let items = list.patterns().collect::<Vec<_>>();
+                assert!(matches!(items[0], Pattern::BindingPattern(_)));
+                assert!(matches!(items[1], Pattern::WildcardPattern(_)));
+            }
+            other => panic!("Expected list pattern, found {other:?}"),
+        }
+        assert!(matches!(patterns[5], Pattern::WildcardPattern(_)));
+    }
+
+    #[test]
+    fn test_match_missing_comma() {
+        let mut context = ParseContext::new("match x { 1 => a 2 => b }");
+
+        super::match_expr.parse(&mut context).unwrap();
+        assert_eq!(context.errors().len(), 1);
+    }
+}
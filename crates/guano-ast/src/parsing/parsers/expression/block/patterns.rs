@@ -0,0 +1,131 @@
+use guano_syntax::{
+    consts::{Keyword, Punctuation},
+    node, Child, SyntaxKind,
+};
+
+use crate::parsing::{
+    combinators::{alternation, tuple, Combinators},
+    error::Res,
+    parsers::{
+        expression::primary::literal::literal,
+        ignorable::{eat_ignorable, IgnorableParser},
+        symbols::{identifier::iden, ty::ty},
+    },
+    ParseContext, Parser,
+};
+
+/// Parse a pattern, as used by `match` arms.
+pub fn pattern<'source>(context: &mut ParseContext<'source>) -> Res<'source, Child> {
+    alternation((
+        nil_pattern,
+        literal_pattern,
+        wildcard_pattern,
+        type_pattern,
+        binding_pattern,
+        list_pattern,
+    ))
+    .parse(context)
+}
+
+pub fn nil_pattern<'source>(context: &mut ParseContext<'source>) -> Res<'source, Child> {
+    let kw = Keyword::NIL.parse(context)?;
+
+    Ok(node(SyntaxKind::NIL_PATTERN, vec![kw]))
+}
+
+pub fn literal_pattern<'source>(context: &mut ParseContext<'source>) -> Res<'source, Child> {
+    let (minus, literal) =
+        tuple((Punctuation::MINUS.then(eat_ignorable).optional(), literal)).parse(context)?;
+
+    let mut children = vec![];
+
+    if let Some((minus, ws)) = minus {
+        children.push(minus);
+        children.extend(ws);
+    }
+
+    children.push(literal);
+
+    Ok(node(SyntaxKind::LITERAL_PATTERN, children))
+}
+
+pub fn wildcard_pattern<'source>(context: &mut ParseContext<'source>) -> Res<'source, Child> {
+    // Names like `_name` are lexed as a single identifier.
+    let underscore = Punctuation::UNDERSCORE.parse(context)?;
+
+    Ok(node(SyntaxKind::WILDCARD_PATTERN, vec![underscore]))
+}
+
+pub fn binding_pattern<'source>(context: &mut ParseContext<'source>) -> Res<'source, Child> {
+    let name = iden(context)?;
+
+    Ok(node(SyntaxKind::BINDING_PATTERN, vec![name]))
+}
+
+/// Parses `is Type` or `name is Type`.
+pub fn type_pattern<'source>(context: &mut ParseContext<'source>) -> Res<'source, Child> {
+    let (name, kw, ws, ty) = tuple((
+        iden.then(eat_ignorable).optional(),
+        Keyword::IS,
+        eat_ignorable,
+        ty.expected(),
+    ))
+    .parse(context)?;
+
+    let mut children = vec![];
+
+    if let Some((name, ws)) = name {
+        children.push(name);
+        children.extend(ws);
+    }
+
+    children.push(kw);
+    children.extend(ws);
+    children.push(ty);
+
+    Ok(node(SyntaxKind::TYPE_PATTERN, children))
+}
+
+pub fn list_pattern<'source>(context: &mut ParseContext<'source>) -> Res<'source, Child> {
+    let first_item = pattern.map(|p| node(SyntaxKind::LIST_PATTERN_ITEM, vec![p]));
+    let other_items = eat_ignorable.then(list_pattern_item).repeated();
+
+    let (l_brack, (l_ws, items, r_ws), r_brack) = tuple((
+        Punctuation::LEFT_BRACK,
+        tuple((first_item, other_items)).optional().padded(),
+        Punctuation::RIGHT_BRACK.expected(),
+    ))
+    .parse(context)?;
+
+    let mut children = vec![l_brack];
+    children.extend(l_ws);
+
+    if let Some((first_item, other_items)) = items {
+        children.push(first_item);
+
+        for (ws, item) in other_items {
+            children.extend(ws);
+            children.push(item);
+        }
+    }
+
+    children.extend(r_ws);
+    children.push(r_brack);
+
+    Ok(node(SyntaxKind::LIST_PATTERN, children))
+}
+
+pub fn list_pattern_item<'source>(context: &mut ParseContext<'source>) -> Res<'source, Child> {
+    let (com, ws, pattern) = tuple((
+        Punctuation::COMMA,
+        eat_ignorable,
+        pattern.expect("Expected pattern"),
+    ))
+    .parse(context)?;
+
+    let mut children = vec![com];
+    children.extend(ws);
+    children.push(pattern);
+
+    Ok(node(SyntaxKind::LIST_PATTERN_ITEM, children))
+}
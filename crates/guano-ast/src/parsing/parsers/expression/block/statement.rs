@@ -25,16 +25,21 @@ pub fn expr_statement<'source>(context: &mut ParseContext<'source>) -> Res<'sour
 
     let mut children = vec![expr];
 
-    if is_block {
-        if let Some((ws, semi)) = eat_ignorable
-            .then(Punctuation::SEMICOLON)
-            .optional()
-            .parse(context)?
-        {
-            children.extend(ws);
-            children.push(semi);
-        }
-    } else {
+    if let Some((ws, semi)) = eat_ignorable
+        .then(Punctuation::SEMICOLON)
+        .optional()
+        .parse(context)?
+    {
+        children.extend(ws);
+        children.push(semi);
+    } else if !is_block {
+        // An expression right before the closing brace is the
+        // block's end expression, so leave it for the block.
+        eat_ignorable
+            .then(Punctuation::RIGHT_CURLY)
+            .not()
+            .parse(context)?;
+
         let (ws, semi) = eat_ignorable
             .then(Punctuation::SEMICOLON.expected())
             .parse(context)?;
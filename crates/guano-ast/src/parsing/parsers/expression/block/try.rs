@@ -0,0 +1,128 @@
+use guano_syntax::{
+    consts::{Keyword, Punctuation},
+    node, Child, SyntaxKind,
+};
+
+use crate::parsing::{
+    combinators::{tuple, Combinators},
+    error::Res,
+    parsers::{
+        ignorable::{eat_ignorable, IgnorableParser},
+        symbols::{identifier::iden, ty::ty},
+    },
+    ParseContext, Parser,
+};
+
+use super::block;
+
+pub fn try_expr<'source>(context: &mut ParseContext<'source>) -> Res<'source, Child> {
+    let (kw, l_ws, block, r_ws, first_catch) = tuple((
+        Keyword::TRY,
+        eat_ignorable,
+        block.expected(),
+        eat_ignorable,
+        catch_clause.expected(),
+    ))
+    .parse(context)?;
+
+    let mut children = vec![kw];
+    children.extend(l_ws);
+    children.push(block);
+    children.extend(r_ws);
+    children.push(first_catch);
+
+    for (ws, catch) in eat_ignorable.then(catch_clause).repeated().parse(context)? {
+        children.extend(ws);
+        children.push(catch);
+    }
+
+    Ok(node(SyntaxKind::TRY_EXPR, children))
+}
+
+pub fn catch_clause<'source>(context: &mut ParseContext<'source>) -> Res<'source, Child> {
+    let (kw, (l_ws, name, r_ws), ty, block) = tuple((
+        Keyword::CATCH,
+        iden.expected().padded(),
+        catch_type.then(eat_ignorable).optional(),
+        block.expected(),
+    ))
+    .parse(context)?;
+
+    let mut children = vec![kw];
+    children.extend(l_ws);
+    children.push(name);
+    children.extend(r_ws);
+
+    if let Some((ty, ws)) = ty {
+        children.push(ty);
+        children.extend(ws);
+    }
+
+    children.push(block);
+
+    Ok(node(SyntaxKind::CATCH_CLAUSE, children))
+}
+
+pub fn catch_type<'source>(context: &mut ParseContext<'source>) -> Res<'source, Child> {
+    let (col, ws, ty) = tuple((Punctuation::COLON, eat_ignorable, ty.expected())).parse(context)?;
+
+    let mut children = vec![col];
+    children.extend(ws);
+    children.push(ty);
+
+    Ok(node(SyntaxKind::CATCH_TYPE, children))
+}
+
+#[cfg(test)]
+mod test {
+    use guano_common::rowan::ast::AstNode;
+    use guano_syntax::{
+        nodes::{Expr, Statement},
+        SyntaxNode,
+    };
+
+    use crate::parsing::{parsers::ignorable::IgnorableParser, ParseContext, Parser};
+
+    #[test]
+    fn test_try() {
+        let source = r#"
+        {
+            try {
+                throw "Something went wrong";
+            } catch e: IoError {
+                print(e);
+            } catch other {
+                throw other;
+            }
+            let after = 1;
+        } "#;
+        let mut context = ParseContext::new(source);
+
+        let (_, node, _) = super::super::block.padded().parse(&mut context).unwrap();
+        assert!(context.errors().is_empty());
+
+        let syntax_node = SyntaxNode::new_root(node.into_node().unwrap());
+        let block = match Expr::cast(syntax_node) {
+            Some(Expr::Block(block)) => block,
+            _ => panic!("Somehow not a block"),
+        };
+        let mut statements = block.statements();
+
+        let try_expr = match statements.next() {
+            Some(Statement::ExprStatement(stmt)) => match stmt.expr() {
+                Some(Expr::TryExpr(try_expr)) => try_expr,
+                other => panic!("Expected try expression, found {other:?}"),
+            },
+            other => panic!("Expected expression statement, found {other:?}"),
+        };
+
+        let catches = try_expr.catches().collect::<Vec<_>>();
+        assert_eq!(catches.len(), 2);
+        assert_eq!(catches[0].name().unwrap().as_str(), "e");
+        assert_eq!(catches[0].ty().unwrap().syntax().to_string(), "IoError");
+        assert_eq!(catches[1].name().unwrap().as_str(), "other");
+        assert!(catches[1].ty().is_none());
+
+        assert!(matches!(statements.next(), Some(Statement::Var(_))));
+    }
+}
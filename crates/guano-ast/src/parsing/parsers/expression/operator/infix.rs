@@ -15,14 +15,18 @@ use crate::parsing::{
 pub fn binary_op<'source>(
     context: &mut ParseContext<'source>,
 ) -> Res<'source, (Child, BinaryKind)> {
-    let ((_, mark), span) = eat_ignorable.then(punctuation)
+    let ((_, mark), span) = eat_ignorable
+        .then(punctuation)
         .spanned()
         .peek()
         .parse(context)?;
     let kind = SyntaxKind::from_u16(mark.kind().0).unwrap();
     if let Some(kind) = BinaryKind::from_syntax(kind) {
         context.advance_byte(u32::from(span.len()) as usize)?;
-        let node = node(SyntaxKind::BINARY_OP, vec![mark]);
+        let node = match kind {
+            BinaryKind::Range(_) => node(SyntaxKind::RANGE_OP, vec![mark]),
+            _ => node(SyntaxKind::BINARY_OP, vec![mark]),
+        };
 
         Ok((node, kind))
     } else {
@@ -51,6 +55,7 @@ pub enum BinaryKind {
     Bitwise(Bitwise),
     Comparison(Comparison),
     Logical(Logical),
+    Range(Range),
     Assignment(Assignment),
 }
 
@@ -64,6 +69,7 @@ impl Infix for BinaryKind {
             BinaryKind::Bitwise(_) => Left,
             BinaryKind::Comparison(_) => Neither,
             BinaryKind::Logical(_) => Left,
+            BinaryKind::Range(_) => Neither,
             BinaryKind::Assignment(_) => Right,
         }
     }
@@ -71,16 +77,17 @@ impl Infix for BinaryKind {
     #[inline]
     fn power(&self) -> Power {
         match self {
-            BinaryKind::Factor(_) => 9,
-            BinaryKind::Term(_) => 8,
+            BinaryKind::Factor(_) => 10,
+            BinaryKind::Term(_) => 9,
             BinaryKind::Bitwise(b) => match b {
-                Bitwise::Shr | Bitwise::Shl => 7,
-                Bitwise::And => 6,
-                Bitwise::Xor => 5,
-                Bitwise::Or => 4,
+                Bitwise::Shr | Bitwise::Shl => 8,
+                Bitwise::And => 7,
+                Bitwise::Xor => 6,
+                Bitwise::Or => 5,
             },
-            BinaryKind::Comparison(_) => 3,
-            BinaryKind::Logical(_) => 2,
+            BinaryKind::Comparison(_) => 4,
+            BinaryKind::Logical(_) => 3,
+            BinaryKind::Range(_) => 2,
             BinaryKind::Assignment(_) => 1,
         }
         .into()
@@ -96,6 +103,7 @@ impl BinaryKind {
             .or_else(|| Bitwise::from_syntax(kind).map(BinaryKind::Bitwise))
             .or_else(|| Comparison::from_syntax(kind).map(BinaryKind::Comparison))
             .or_else(|| Logical::from_syntax(kind).map(BinaryKind::Logical))
+            .or_else(|| Range::from_syntax(kind).map(BinaryKind::Range))
             .or_else(|| Assignment::from_syntax(kind).map(BinaryKind::Assignment))
     }
 }
@@ -105,11 +113,12 @@ pub trait BinaryExt {
 }
 
 impl BinaryExt for BinaryOp {
+    /// The kind of the operator token inside of this node.
     fn kind(&self) -> BinaryKind {
-        let kind = self.syntax().kind();
-        let kind = BinaryKind::from_syntax(kind).expect("Invalid binary operator");
-
-        kind
+        self.syntax()
+            .children_with_tokens()
+            .find_map(|t| BinaryKind::from_syntax(t.kind()))
+            .expect("Invalid binary operator")
     }
 }
 
@@ -262,6 +271,25 @@ impl Bitwise {
     }
 }
 
+/// A range, such as `a..b` or `a..=b`, which may have a step after `by`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Range {
+    Exclusive,
+    Inclusive,
+}
+
+impl Range {
+    pub fn from_syntax(kind: SyntaxKind) -> Option<Self> {
+        use SyntaxKind::*;
+
+        Some(match kind {
+            DOT2 => Self::Exclusive,
+            DOT2_EQ => Self::Inclusive,
+            _ => return None,
+        })
+    }
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum Assignment {
     Assign,
@@ -285,3 +313,106 @@ impl Assignment {
         }
     }
 }
+
+#[cfg(test)]
+mod test {
+    use guano_common::rowan::ast::AstNode;
+    use guano_syntax::{
+        consts::Punctuation,
+        nodes::{BinaryOp, Expr, RangeExpr},
+        SyntaxKind, SyntaxNode,
+    };
+
+    use crate::parsing::{lexer::lex, parsers::expression::expr, ParseContext, Parser};
+
+    use super::{BinaryExt, BinaryKind};
+
+    fn parse_range(source: &str) -> RangeExpr {
+        let mut context = ParseContext::new(source);
+        let node = expr.parse(&mut context).unwrap();
+        assert!(context.errors().is_empty(), "{:?}", context.errors());
+        assert!(context.is_eof(), "Remaining: {:?}", context.remaining());
+
+        match Expr::cast(SyntaxNode::new_root(node.into_node().unwrap())) {
+            Some(Expr::RangeExpr(range)) => range,
+            other => panic!("Expected range, found {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_range() {
+        let range = parse_range("0..10");
+        assert_eq!(range.start().unwrap().syntax().to_string(), "0");
+        assert_eq!(range.end().unwrap().syntax().to_string(), "10");
+        assert!(!range.inclusive());
+        assert!(range.step().is_none());
+
+        let range = parse_range("a + 1 ..= b * 2 by n - 1");
+        assert_eq!(range.start().unwrap().syntax().to_string(), "a + 1");
+        assert_eq!(range.end().unwrap().syntax().to_string(), "b * 2");
+        assert!(range.inclusive());
+        assert_eq!(range.step().unwrap().syntax().to_string(), "n - 1");
+
+        let range = parse_range("list.len..0 by -1");
+        assert!(matches!(range.start(), Some(Expr::FieldExpr(_))));
+
+        let mut context = ParseContext::new("for i in 0..10 by 2 { print(i); }");
+        match expr.parse(&mut context).unwrap().into_node() {
+            Some(node) => assert_eq!(node.kind(), guano_syntax::SyntaxKind::FOR_EXPR.into()),
+            None => panic!("Expected for loop"),
+        }
+        assert!(context.errors().is_empty(), "{:?}", context.errors());
+
+        // `by` is only special after a range.
+        let range = parse_range("0..by by by");
+        assert_eq!(range.end().unwrap().syntax().to_string(), "by");
+        assert_eq!(range.step().unwrap().syntax().to_string(), "by");
+
+        let (context, _) = crate::parse_file("fun f() {\n    let by = 2;\n    sort_by(by);\n}\n");
+        assert!(context.errors().is_empty(), "{:?}", context.errors());
+    }
+
+    #[test]
+    fn test_compound_operators() {
+        let operators = Punctuation::ALL
+            .iter()
+            .filter(|p| BinaryKind::from_syntax(p.syntax_kind()).is_some());
+
+        for op in operators {
+            for source in [
+                format!("a{op}b", op = op.as_str()),
+                format!("a {} b", op.as_str()),
+            ] {
+                let kinds = lex(&source)
+                    .tokens
+                    .into_iter()
+                    .map(|t| t.kind)
+                    .filter(|k| *k != SyntaxKind::WHITESPACE)
+                    .collect::<Vec<_>>();
+                assert_eq!(
+                    kinds,
+                    [SyntaxKind::IDEN, op.syntax_kind(), SyntaxKind::IDEN],
+                    "{source:?}"
+                );
+
+                let mut context = ParseContext::new(&source);
+                let node = expr.parse(&mut context).unwrap();
+                assert!(context.errors().is_empty(), "{:?}", context.errors());
+                assert!(context.is_eof(), "Remaining: {:?}", context.remaining());
+
+                let op_node = SyntaxNode::new_root(node.into_node().unwrap())
+                    .children()
+                    .find(|n| matches!(n.kind(), SyntaxKind::BINARY_OP | SyntaxKind::RANGE_OP))
+                    .unwrap_or_else(|| panic!("No operator in {source:?}"));
+                assert_eq!(op_node.to_string(), op.as_str());
+
+                if let Some(binary) = BinaryOp::cast(op_node) {
+                    assert_eq!(
+                        Some(binary.kind()),
+                        BinaryKind::from_syntax(op.syntax_kind())
+                    );
+                }
+            }
+        }
+    }
+}
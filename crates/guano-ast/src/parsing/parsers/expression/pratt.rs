@@ -1,14 +1,18 @@
-use guano_syntax::{node, Child, SyntaxKind};
+use guano_syntax::{leaf, node, Child, SyntaxKind};
 
 use crate::parsing::{
     combinators::{tuple, Combinators},
-    error::Res,
+    error::{Error, Res},
     parsers::ignorable::eat_ignorable,
     ParseContext, Parser,
 };
 
 use super::{
-    operator::{infix::binary_op, postfix::postfix_operator, prefix::unary_op},
+    operator::{
+        infix::{binary_op, BinaryKind},
+        postfix::postfix_operator,
+        prefix::unary_op,
+    },
     primary::primary,
 };
 
@@ -90,6 +94,11 @@ fn pratt_infix<'source>(
             .expect("Expected expression")
             .parse(context)?;
 
+        let step = match kind {
+            BinaryKind::Range(_) => range_step(right_bp).optional().parse(context)?,
+            _ => None,
+        };
+
         take_mut::take(lhs, |lhs| {
             let mut children = vec![lhs];
             children.extend(left_ws);
@@ -97,7 +106,15 @@ fn pratt_infix<'source>(
             children.extend(right_ws);
             children.push(rhs);
 
-            let binary = node(SyntaxKind::BINARY_EXPR, children);
+            if let Some((ws, step)) = step {
+                children.extend(ws);
+                children.push(step);
+            }
+
+            let binary = match kind {
+                BinaryKind::Range(_) => node(SyntaxKind::RANGE_EXPR, children),
+                _ => node(SyntaxKind::BINARY_EXPR, children),
+            };
             // let expr = node(SyntaxKind::EXPR, vec![binary]);
 
             binary
@@ -109,6 +126,38 @@ fn pratt_infix<'source>(
     }
 }
 
+/// Parse the ` by step` of a range, including the leading whitespace.
+fn range_step<'source>(
+    power: Power,
+) -> impl FnMut(&mut ParseContext<'source>) -> Res<'source, (Vec<Child>, Child)> {
+    move |context| {
+        let (l_ws, kw, r_ws, step) = tuple((
+            eat_ignorable,
+            by,
+            eat_ignorable,
+            pratt_expr(power).expect("Expected step"),
+        ))
+        .parse(context)?;
+
+        let mut children = vec![kw];
+        children.extend(r_ws);
+        children.push(step);
+
+        Ok((l_ws, node(SyntaxKind::RANGE_STEP, children)))
+    }
+}
+
+/// Parse the `by` of a range step. It is only special after a range,
+/// so it is lexed as an identifier and can still be used as a name.
+fn by<'source>(context: &mut ParseContext<'source>) -> Res<'source, Child> {
+    match context.token() {
+        Some(token) if token.kind == SyntaxKind::IDEN && &context.source()[token.range] == "by" => {
+            Ok(leaf(SyntaxKind::IDEN, context.consume(token)))
+        }
+        _ => Err(Error::expected(context.span(), "'by'")),
+    }
+}
+
 fn pratt_prefix<'source>(context: &mut ParseContext<'source>) -> Res<'source, Child> {
     let maybe_operator = unary_op.then(eat_ignorable).optional().parse(context)?;
 
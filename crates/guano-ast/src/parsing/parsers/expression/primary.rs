@@ -4,12 +4,13 @@ use crate::parsing::{
     combinators::alternation, error::Res, parsers::symbols::path, ParseContext, Parser,
 };
 
-use self::keyword::{break_expr, continue_expr, return_expr};
+use self::keyword::{break_expr, continue_expr, return_expr, throw_expr};
 
 use super::block::block;
 
 pub mod group;
 pub mod keyword;
+pub mod lambda;
 pub mod list;
 pub mod literal;
 
@@ -20,10 +21,13 @@ pub fn primary<'source>(context: &mut ParseContext<'source>) -> Res<'source, Chi
         group::group_expr,
         list::list_expr,
         literal::literal,
+        literal::string::interpolated_string,
+        lambda::lambda_expr,
         path::path,
         return_expr,
         continue_expr,
         break_expr,
+        throw_expr,
     ))
     .parse(context)
 }
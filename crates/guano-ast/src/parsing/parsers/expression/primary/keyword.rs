@@ -1,7 +1,7 @@
 use guano_syntax::{consts::Keyword, node, Child, SyntaxKind};
 
 use crate::parsing::{
-    combinators::Combinators,
+    combinators::{tuple, Combinators},
     error::Res,
     parsers::{expression::expr, ignorable::eat_ignorable},
     ParseContext, Parser,
@@ -21,6 +21,21 @@ pub fn return_expr<'source>(context: &mut ParseContext<'source>) -> Res<'source,
     Ok(node(SyntaxKind::RETURN_EXPR, children))
 }
 
+pub fn throw_expr<'source>(context: &mut ParseContext<'source>) -> Res<'source, Child> {
+    let (kw, ws, expr) = tuple((
+        Keyword::THROW,
+        eat_ignorable,
+        expr.expect("Expected expression"),
+    ))
+    .parse(context)?;
+
+    let mut children = vec![kw];
+    children.extend(ws);
+    children.push(expr);
+
+    Ok(node(SyntaxKind::THROW_EXPR, children))
+}
+
 pub fn continue_expr<'source>(context: &mut ParseContext<'source>) -> Res<'source, Child> {
     Ok(node(
         SyntaxKind::CONTINUE_EXPR,
@@ -0,0 +1,88 @@
+use guano_syntax::{consts::Keyword, node, Child, SyntaxKind};
+
+use crate::parsing::{
+    combinators::{tuple, Combinators},
+    error::Res,
+    parsers::{
+        declaration::function::{func_params, func_type},
+        expression::block::block,
+        ignorable::eat_ignorable,
+    },
+    ParseContext, Parser,
+};
+
+/// Parses an anonymous function, such as `fun(x: int) -> int { x * 2 }`.
+pub fn lambda_expr<'source>(context: &mut ParseContext<'source>) -> Res<'source, Child> {
+    let (kw, params, ty, ws, block) = tuple((
+        Keyword::FUN,
+        eat_ignorable.then(func_params).optional(),
+        eat_ignorable.then(func_type).optional(),
+        eat_ignorable,
+        block.expected(),
+    ))
+    .parse(context)?;
+
+    let mut children = vec![kw];
+
+    if let Some((ws, params)) = params {
+        children.extend(ws);
+        children.push(params);
+    }
+
+    if let Some((ws, ty)) = ty {
+        children.extend(ws);
+        children.push(ty);
+    }
+
+    children.extend(ws);
+    children.push(block);
+
+    Ok(node(SyntaxKind::LAMBDA_EXPR, children))
+}
+
+#[cfg(test)]
+mod test {
+    use guano_common::rowan::ast::AstNode;
+    use guano_syntax::{
+        nodes::{Expr, Type, Var},
+        SyntaxNode,
+    };
+
+    use crate::parsing::{parsers::declaration::variable::var, ParseContext, Parser};
+
+    #[test]
+    fn test_lambda() {
+        let source = "let double: fun(int) -> int = fun(x: int) -> int { x * 2 };";
+        let mut context = ParseContext::new(source);
+        let node = var.parse(&mut context).unwrap();
+        assert!(context.errors().is_empty(), "{:?}", context.errors());
+
+        let var = Var::cast(SyntaxNode::new_root(node.into_node().unwrap())).unwrap();
+
+        let ty = match var.ty() {
+            Some(Type::FunctionType(ty)) => ty,
+            other => panic!("Expected function type, found {other:?}"),
+        };
+        let params = ty
+            .params()
+            .map(|p| p.syntax().to_string())
+            .collect::<Vec<_>>();
+        assert_eq!(params, ["int"]);
+        assert_eq!(ty.ty().unwrap().syntax().to_string(), "int");
+
+        let lambda = match var.value() {
+            Some(Expr::LambdaExpr(lambda)) => lambda,
+            other => panic!("Expected lambda, found {other:?}"),
+        };
+        let params = lambda
+            .params()
+            .unwrap()
+            .map(|(name, ty)| (name.as_str().to_owned(), ty.syntax().to_string()))
+            .collect::<Vec<_>>();
+        assert_eq!(params, [("x".to_owned(), "int".to_owned())]);
+        assert_eq!(lambda.ty().unwrap().syntax().to_string(), "int");
+
+        let end = lambda.block().unwrap().end_expr().unwrap();
+        assert_eq!(end.syntax().to_string(), "x * 2");
+    }
+}
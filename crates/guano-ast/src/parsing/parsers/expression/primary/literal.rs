@@ -19,6 +19,97 @@ pub fn literal<'source>(context: &mut ParseContext<'source>) -> Res<'source, Chi
         string::string_lazy,
         number::number_lazy,
     ))
+    .named("literal")
     .map(|n| node(SyntaxKind::LITERAL, vec![n]))
     .parse(context)
 }
+
+#[cfg(test)]
+mod test {
+    use guano_common::{
+        num::rug::Integer,
+        rowan::{ast::AstNode, TextRange},
+    };
+    use guano_syntax::{
+        nodes::Literal,
+        tokens::{LiteralError, LiteralErrorKind},
+        SyntaxNode,
+    };
+
+    use crate::parsing::{ParseContext, Parser};
+
+    fn parse_literal(source: &str) -> Literal {
+        let mut context = ParseContext::new(source);
+        let node = super::literal.parse(&mut context).unwrap();
+        assert!(context.errors().is_empty(), "{:?}", context.errors());
+        assert!(context.is_eof());
+
+        Literal::cast(SyntaxNode::new_root(node.into_node().unwrap())).unwrap()
+    }
+
+    fn string(source: &str) -> Result<String, Vec<LiteralError>> {
+        parse_literal(source).string().unwrap().value()
+    }
+
+    fn integer(source: &str) -> Integer {
+        parse_literal(source).integer().unwrap().value().unwrap()
+    }
+
+    #[test]
+    fn test_string_values() {
+        assert_eq!(
+            string(r#""tab\t quote\" \{brace\} \x41é\U01F600""#).unwrap(),
+            "tab\t quote\" {brace} A\u{e9}\u{1F600}"
+        );
+
+        let errors = string(r#""bad \q \xFF \uD800""#).unwrap_err();
+        let errors = errors
+            .into_iter()
+            .map(|e| (e.span, e.kind))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            errors,
+            [
+                (
+                    TextRange::new(5.into(), 7.into()),
+                    LiteralErrorKind::InvalidEscape
+                ),
+                (
+                    TextRange::new(8.into(), 12.into()),
+                    LiteralErrorKind::InvalidByteEscape(0xFF)
+                ),
+                (
+                    TextRange::new(13.into(), 19.into()),
+                    LiteralErrorKind::InvalidCodePoint(0xD800)
+                ),
+            ]
+        );
+
+        let (lossy, _) = parse_literal(r#""a\qb""#).string().unwrap().value_lossy();
+        assert_eq!(lossy, format!("a{}b", super::char::REPLACEMENT_STRING));
+    }
+
+    #[test]
+    fn test_char_values() {
+        let char = |source| parse_literal(source).char().unwrap().value();
+
+        assert_eq!(char("'a'").unwrap(), 'a');
+        assert_eq!(char(r"'\n'").unwrap(), '\n');
+        assert_eq!(char(r"'é'").unwrap(), '\u{e9}');
+        assert_eq!(char("''").unwrap_err()[0].kind, LiteralErrorKind::EmptyChar);
+        assert_eq!(
+            char("'ab'").unwrap_err()[0].kind,
+            LiteralErrorKind::MultipleChars
+        );
+    }
+
+    #[test]
+    fn test_number_values() {
+        assert_eq!(integer("1_000_000"), 1_000_000);
+        assert_eq!(integer("0b1010_1010"), 0b1010_1010);
+        assert_eq!(integer("0xDEAD_beef"), 0xDEAD_BEEF_u32);
+
+        let float = parse_literal("1_000.5").float().unwrap().value().unwrap();
+        assert_eq!(float, 1_000.5);
+    }
+}
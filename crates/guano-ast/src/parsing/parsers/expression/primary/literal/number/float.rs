@@ -1,13 +1,13 @@
 use guano_syntax::{leaf, Child, SyntaxKind};
 
 use crate::parsing::{
-    combinators::{regex, Combinators},
+    combinators::{token, Combinators},
     error::Res,
     ParseContext, Parser,
 };
 
 pub fn float_lazy<'source>(context: &mut ParseContext<'source>) -> Res<'source, Child> {
-    regex(super::FLOAT_REGEX)
+    token(SyntaxKind::LIT_FLOAT)
         .map(|float| leaf(SyntaxKind::LIT_FLOAT, float))
         .parse(context)
 }
@@ -1,13 +1,13 @@
 use guano_syntax::{leaf, Child, SyntaxKind};
 
 use crate::parsing::{
-    combinators::{regex, Combinators},
+    combinators::{token, Combinators},
     error::Res,
     ParseContext, Parser,
 };
 
 pub fn integer_lazy<'source>(context: &mut ParseContext<'source>) -> Res<'source, Child> {
-    regex(super::INTEGER_REGEX)
+    token(SyntaxKind::LIT_INTEGER)
         .map(|int| leaf(SyntaxKind::LIT_INTEGER, int))
         .parse(context)
 }
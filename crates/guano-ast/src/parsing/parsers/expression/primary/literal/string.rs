@@ -1,15 +1,110 @@
-use guano_syntax::{leaf, Child, SyntaxKind};
+use guano_syntax::{consts::Punctuation, leaf, node, Child, SyntaxKind};
 
 use crate::parsing::{
-    combinators::{regex, Combinators},
+    combinators::{alternation, token, tuple, Combinators},
     error::Res,
+    parsers::{expression::expr, ignorable::IgnorableParser},
     ParseContext, Parser,
 };
 
-use super::char::regex::STRING_LAZY;
-
 pub fn string_lazy<'source>(context: &mut ParseContext<'source>) -> Res<'source, Child> {
-    regex(STRING_LAZY)
+    token(SyntaxKind::LIT_STRING)
         .map(|text| leaf(SyntaxKind::LIT_STRING, text))
         .parse(context)
 }
+
+/// Parse a string containing interpolations, such as `"Hello {name}"`.
+///
+/// Strings without any interpolations are left to [`string_lazy`].
+pub fn interpolated_string<'source>(context: &mut ParseContext<'source>) -> Res<'source, Child> {
+    let (l_quote, parts, r_quote) = tuple((
+        Punctuation::QUOTE,
+        alternation((string_fragment, interpolation)).repeated(),
+        Punctuation::QUOTE.expected(),
+    ))
+    .parse(context)?;
+
+    let mut children = vec![l_quote];
+    children.extend(parts);
+    children.push(r_quote);
+
+    Ok(node(SyntaxKind::INTERPOLATED_STRING, children))
+}
+
+pub fn string_fragment<'source>(context: &mut ParseContext<'source>) -> Res<'source, Child> {
+    token(SyntaxKind::LIT_STRING_FRAGMENT)
+        .map(|text| leaf(SyntaxKind::LIT_STRING_FRAGMENT, text))
+        .parse(context)
+}
+
+pub fn interpolation<'source>(context: &mut ParseContext<'source>) -> Res<'source, Child> {
+    let (l_curly, (l_ws, expr, r_ws), r_curly) = tuple((
+        Punctuation::LEFT_CURLY,
+        expr.expected().padded(),
+        Punctuation::RIGHT_CURLY.expected(),
+    ))
+    .parse(context)?;
+
+    let mut children = vec![l_curly];
+    children.extend(l_ws);
+    children.push(expr);
+    children.extend(r_ws);
+    children.push(r_curly);
+
+    Ok(node(SyntaxKind::INTERPOLATION, children))
+}
+
+#[cfg(test)]
+mod test {
+    use guano_common::rowan::ast::AstNode;
+    use guano_syntax::{
+        nodes::{Expr, StringPart},
+        AstToken, SyntaxNode,
+    };
+
+    use crate::parsing::{parsers::expression::expr, ParseContext, Parser};
+
+    fn parse_expr(source: &str) -> Expr {
+        let mut context = ParseContext::new(source);
+        let node = expr.parse(&mut context).unwrap();
+        assert!(context.errors().is_empty(), "{:?}", context.errors());
+        assert!(context.is_eof(), "Remaining: {:?}", context.remaining());
+
+        Expr::cast(SyntaxNode::new_root(node.into_node().unwrap())).unwrap()
+    }
+
+    #[test]
+    fn test_interpolated_string() {
+        let string = match parse_expr(r#""Hello {name}, you are {age + 1}\{!\}""#) {
+            Expr::InterpolatedString(string) => string,
+            other => panic!("Expected interpolated string, found {other:?}"),
+        };
+
+        let parts = string
+            .parts()
+            .map(|part| match part {
+                StringPart::Fragment(fragment) => fragment.text().to_owned(),
+                StringPart::Interpolation(interpolation) => {
+                    format!("<{}>", interpolation.expr().unwrap().syntax())
+                }
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(
+            parts,
+            ["Hello ", "<name>", ", you are ", "<age + 1>", r"\{!\}"]
+        );
+
+        match parse_expr(r#""{ {"nested {1}"} }""#) {
+            Expr::InterpolatedString(string) => assert_eq!(string.parts().count(), 1),
+            other => panic!("Expected interpolated string, found {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_plain_string() {
+        match parse_expr(r#""no \{interpolation} here""#) {
+            Expr::Literal(literal) => assert!(literal.string().is_some()),
+            other => panic!("Expected string literal, found {other:?}"),
+        }
+    }
+}
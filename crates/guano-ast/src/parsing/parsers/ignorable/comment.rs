@@ -1,8 +1,9 @@
+use guano_common::rowan::NodeOrToken;
 use guano_syntax::{leaf, Child, SyntaxKind};
 
 use crate::parsing::{
-    combinators::{alternation, regex},
-    error::Res,
+    combinators::{alternation, errors::CombinatorError},
+    error::{Error, Res},
     ParseContext, Parser,
 };
 
@@ -11,15 +12,153 @@ pub fn comment<'source>(context: &mut ParseContext<'source>) -> Res<'source, Chi
 }
 
 pub fn line_comment<'source>(context: &mut ParseContext<'source>) -> Res<'source, Child> {
-    let comment = regex(r"^//[^\n]*\n?").parse(context)?;
-
-    Ok(leaf(SyntaxKind::COMMENT, comment))
+    comment_starting_with("//", context)
 }
 
 pub fn block_comment<'source>(context: &mut ParseContext<'source>) -> Res<'source, Child> {
-    // let comment = block_comment_inner(context)?;
-    // let comment = regex(r"^/*(?:[^*]|\*[^/])*\*/").parse(context)?;
-    let comment = regex(r"^/[*](?:[^*]|(?:[*][^/]))*[*]+/").parse(context)?;
+    comment_starting_with("/*", context)
+}
+
+fn comment_starting_with<'source>(
+    start: &'static str,
+    context: &mut ParseContext<'source>,
+) -> Res<'source, Child> {
+    match context.token() {
+        Some(token)
+            if token.kind.is_comment() && context.source()[token.range].starts_with(start) =>
+        {
+            Ok(leaf(token.kind, context.consume(token)))
+        }
+        _ => Err(Error::spanned(context.span(), CombinatorError::Tag(start))),
+    }
+}
+
+/// Declarations that the doc comments before them are moved into.
+const DOCUMENTED: &[SyntaxKind] = &[
+    SyntaxKind::CLASS,
+    SyntaxKind::CLASS_FIELD,
+    SyntaxKind::ENUM,
+    SyntaxKind::ENUM_VARIANT,
+    SyntaxKind::FUNC,
+    SyntaxKind::MODULE,
+    SyntaxKind::PROTO,
+    SyntaxKind::VAR,
+];
+
+/// Move the outer doc comments before each declaration in `children`,
+/// along with the whitespace between them, into the declaration's node.
+pub fn attach_docs(children: Vec<Child>) -> Vec<Child> {
+    let mut attached: Vec<Child> = Vec::with_capacity(children.len());
+
+    for child in children {
+        let node = match child {
+            NodeOrToken::Node(node)
+                if DOCUMENTED.iter().any(|&kind| node.kind() == kind.into()) =>
+            {
+                node
+            }
+            child => {
+                attached.push(child);
+                continue;
+            }
+        };
+
+        let mut first_doc = None;
+        for (i, child) in attached.iter().enumerate().rev() {
+            match child {
+                NodeOrToken::Token(t) if t.kind() == SyntaxKind::DOC_COMMENT.into() => {
+                    first_doc = Some(i)
+                }
+                NodeOrToken::Token(t) if t.kind() == SyntaxKind::WHITESPACE.into() => {}
+                _ => break,
+            }
+        }
+
+        let node = match first_doc {
+            Some(first) => node.splice_children(0..0, attached.split_off(first)),
+            None => node,
+        };
+
+        attached.push(NodeOrToken::Node(node));
+    }
+
+    attached
+}
+
+#[cfg(test)]
+mod test {
+    use guano_common::rowan::ast::AstNode;
+    use guano_syntax::{nodes::*, HasDocs};
+
+    fn find<N: AstNode<Language = guano_syntax::Lang>>(file: &SourceFile) -> N {
+        file.syntax().descendants().find_map(N::cast).unwrap()
+    }
+
+    #[test]
+    fn test_attach_docs() {
+        let source = "//! The file.
+/// Adds.
+///
+///  Indented.
+pub fun add() {
+    /** The sum. */
+    let sum = 1;
+}
+
+// Not a doc comment.
+/// A point.
+class Point {
+    /// The x coordinate.
+    x: int;
+    y: int;
+}
+
+/// A shape.
+enum Shape {
+    /// Round.
+    Circle,
+    Square,
+}
+
+/// Dangling.
+
+module math {
+    //! Math things.
+}
+";
+        let (context, file) = crate::parse_file(source);
+        assert!(context.errors().is_empty());
+        assert_eq!(file.to_string(), source);
+
+        assert_eq!(file.docs().as_deref(), Some("The file."));
+        assert_eq!(
+            find::<Func>(&file).docs().as_deref(),
+            Some("Adds.\n\n Indented.")
+        );
+        assert!(find::<Func>(&file)
+            .syntax()
+            .text()
+            .to_string()
+            .starts_with("/// Adds."));
+        assert_eq!(find::<Var>(&file).docs().as_deref(), Some("The sum."));
+        assert_eq!(find::<Class>(&file).docs().as_deref(), Some("A point."));
+
+        let mut fields = file.syntax().descendants().filter_map(ClassField::cast);
+        assert_eq!(
+            fields.next().unwrap().docs().as_deref(),
+            Some("The x coordinate.")
+        );
+        assert_eq!(fields.next().unwrap().docs(), None);
+
+        assert_eq!(find::<Enum>(&file).docs().as_deref(), Some("A shape."));
+        let mut variants = find::<Enum>(&file).variants();
+        assert_eq!(variants.next().unwrap().docs().as_deref(), Some("Round."));
+        assert_eq!(variants.next().unwrap().docs(), None);
 
-    Ok(leaf(SyntaxKind::COMMENT, comment))
+        // Blank lines don't separate doc comments from what they document.
+        assert_eq!(
+            find::<Module>(&file).docs().as_deref(),
+            Some("Dangling.\nMath things.")
+        );
+    }
 }
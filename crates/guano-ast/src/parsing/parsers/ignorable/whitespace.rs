@@ -1,9 +1,9 @@
 use guano_syntax::{leaf, Child, SyntaxKind};
 
-use crate::parsing::{combinators::regex, error::Res, ParseContext, Parser};
+use crate::parsing::{combinators::token, error::Res, ParseContext, Parser};
 
 pub fn whitespace<'source>(context: &mut ParseContext<'source>) -> Res<'source, Child> {
-    let whitespace = regex(r"^\s+").parse(context)?;
+    let whitespace = token(SyntaxKind::WHITESPACE).parse(context)?;
 
     Ok(leaf(SyntaxKind::WHITESPACE, whitespace))
 }
@@ -9,7 +9,7 @@ use crate::parsing::{
 use super::{
     declaration::function::funcs,
     ignorable::eat_ignorable,
-    symbols::{path::path, ty::ty},
+    symbols::{path::type_path, ty::ty},
 };
 
 pub fn implementation<'source>(context: &mut ParseContext<'source>) -> Res<'source, Child> {
@@ -39,7 +39,7 @@ pub fn implementation<'source>(context: &mut ParseContext<'source>) -> Res<'sour
 }
 
 pub fn impl_proto<'source>(context: &mut ParseContext<'source>) -> Res<'source, Child> {
-    let (path, ws, kw) = tuple((path, eat_ignorable, Keyword::ON)).parse(context)?;
+    let (path, ws, kw) = tuple((type_path, eat_ignorable, Keyword::ON)).parse(context)?;
     let mut children = vec![path];
 
     children.extend(ws);
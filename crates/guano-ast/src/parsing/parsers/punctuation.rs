@@ -1,22 +1,20 @@
+use std::borrow::Cow;
+
 use guano_syntax::{consts, leaf, Child};
 
 use crate::parsing::{
-    combinators::{tag, Combinators},
     error::{Error, ErrorKind, Res},
     ParseContext, Parser,
 };
 
 pub fn punctuation<'source>(context: &mut ParseContext<'source>) -> Res<'source, Child> {
-    for mark in consts::Punctuation::ALL {
-        match tag(mark.as_str()).optional().parse(context)? {
-            Some(text) => return Ok(leaf(mark.syntax_kind(), text)),
-            None => continue,
+    match context.token() {
+        Some(token) if token.kind.is_punctuation() => Ok(leaf(token.kind, context.consume(token))),
+        _ => {
+            let kind = ErrorKind::String("Expected punctuation".into());
+            Err(Error::spanned(context.span(), kind))
         }
     }
-
-    let kind = ErrorKind::String("Expected punctuation".into());
-
-    Err(Error::spanned(context.span(), kind))
 }
 
 impl<'source> Parser<'source> for consts::Punctuation {
@@ -24,15 +22,22 @@ impl<'source> Parser<'source> for consts::Punctuation {
     type Error = Error<'source>;
 
     fn parse(self, context: &mut ParseContext<'source>) -> Result<Self::Output, Self::Error> {
-        let (punct, span) = punctuation.spanned().peek().parse(context)?;
-        if punct.kind().0 == self.syntax_kind() as u16 {
-            *context.position_mut() += span.len();
-
-            Ok(punct)
-        } else {
-            let kind =
-                ErrorKind::String(format!("Expected punctuation {:?}", self.as_str()).into());
-            Err(Error::spanned(context.span(), kind))
-        }
+        context.trace_enter(|| self.name());
+
+        let result = match context.token() {
+            Some(token) if token.kind == self.syntax_kind() => {
+                Ok(leaf(token.kind, context.consume(token)))
+            }
+            _ => Err(Error::expected(context.span(), self.name())),
+        };
+
+        context.trace_exit(result.is_ok());
+
+        result
+    }
+
+    #[inline]
+    fn name(&self) -> Cow<'static, str> {
+        format!("'{}'", self.as_str()).into()
     }
 }
@@ -0,0 +1,163 @@
+use guano_common::rowan::TextRange;
+use guano_syntax::{consts::Keyword, leaf, node, Child, SyntaxKind};
+
+use crate::parsing::{
+    error::{Error, ErrorKind, Res},
+    ParseContext, Parser,
+};
+
+/// Keywords that can start a module item, used to resynchronize after a broken item.
+const ITEM_KEYWORDS: &[Keyword] = &[
+    Keyword::PUB,
+    Keyword::FUN,
+    Keyword::CLASS,
+    Keyword::PROTO,
+    Keyword::ENUM,
+    Keyword::MODULE,
+    Keyword::IMPORT,
+    Keyword::IMPL,
+    Keyword::LET,
+    Keyword::VAR,
+];
+
+/// Where skipping should stop, besides a `;` or an unmatched `}`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SyncPoint {
+    Statement,
+    Item,
+}
+
+/// Skip input until the next statement boundary, and wrap it in an `ERROR` node.
+pub fn recover_statement<'source>(context: &mut ParseContext<'source>) -> Res<'source, Child> {
+    recover(SyncPoint::Statement, "Expected statement or expression").parse(context)
+}
+
+/// Skip input until the next item boundary, and wrap it in an `ERROR` node.
+pub fn recover_item<'source>(context: &mut ParseContext<'source>) -> Res<'source, Child> {
+    recover(SyncPoint::Item, "Expected item").parse(context)
+}
+
+/// Skip input until `sync` is reached, reporting the skipped text as an error.
+///
+/// Skipping always consumes at least one token, keeps braces, brackets
+/// and parentheses balanced, and stops right after a `;` or right before
+/// an unmatched `}`. Strings, chars and comments are single tokens,
+/// so the braces inside of them don't count.
+///
+/// Fails if there is nothing left to skip.
+pub fn recover<'source>(
+    sync: SyncPoint,
+    message: &'static str,
+) -> impl FnMut(&mut ParseContext<'source>) -> Res<'source, Child> + Copy {
+    move |context| {
+        let start = context.position();
+        // Trailing whitespace is left for the caller.
+        let mut end = start;
+        let mut depth = 0usize;
+
+        while let Some(token) = context.token() {
+            let is_first = context.position() == start;
+
+            if depth == 0 && !is_first && is_sync_point(sync, token.kind) {
+                break;
+            }
+
+            context.consume(token);
+
+            if !token.kind.is_trivia() {
+                end = context.position();
+            }
+
+            match token.kind {
+                SyntaxKind::LEFT_CURLY | SyntaxKind::LEFT_PAREN | SyntaxKind::LEFT_BRACK => {
+                    depth += 1
+                }
+                SyntaxKind::RIGHT_CURLY | SyntaxKind::RIGHT_PAREN | SyntaxKind::RIGHT_BRACK => {
+                    depth = depth.saturating_sub(1)
+                }
+                SyntaxKind::SEMICOLON if depth == 0 => break,
+                _ => {}
+            }
+        }
+
+        *context.position_mut() = end;
+        let range = TextRange::new(start, end);
+
+        if range.is_empty() {
+            let kind = ErrorKind::String("Nothing to recover from".into());
+
+            return Err(Error::spanned(context.span(), kind));
+        }
+
+        context.report_error(Error::spanned(range, ErrorKind::Skipped(message.into())));
+
+        let text = &context.source()[range];
+
+        Ok(node(SyntaxKind::ERROR, vec![leaf(SyntaxKind::ERROR, text)]))
+    }
+}
+
+fn is_sync_point(sync: SyncPoint, kind: SyntaxKind) -> bool {
+    kind == SyntaxKind::RIGHT_CURLY
+        || sync == SyncPoint::Item && ITEM_KEYWORDS.iter().any(|kw| kw.syntax_kind() == kind)
+}
+
+#[cfg(test)]
+mod test {
+    use guano_common::rowan::ast::AstNode;
+    use guano_syntax::{
+        nodes::{Decl, ModuleItem},
+        SyntaxKind,
+    };
+
+    #[test]
+    fn test_recovery() {
+        let source = r#"
+        fun broken() {
+            let a = 1;
+            ) ] garbage "with } braces" here;
+            let b = 2;
+        }
+
+        @@@ { nested { } } stray
+
+        }
+
+        fun after() -> int { 1 }"#;
+
+        let (context, file) = crate::parse_file(source);
+        assert!(context.is_eof());
+        assert_eq!(file.syntax().to_string(), source);
+        assert_eq!(context.errors().len(), 3, "{:?}", context.errors());
+
+        let errors = file
+            .syntax()
+            .descendants()
+            .filter(|n| n.kind() == SyntaxKind::ERROR)
+            .map(|n| n.to_string())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            errors,
+            [
+                r#") ] garbage "with } braces" here;"#,
+                "@@@ { nested { } } stray",
+                "}",
+            ]
+        );
+
+        let names = file
+            .items()
+            .filter_map(|item| match item {
+                ModuleItem::Decl(Decl::Func(func)) => Some(func.name()?.as_str().to_owned()),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(names, ["broken", "after"]);
+
+        let statements = match &file.items().next() {
+            Some(ModuleItem::Decl(Decl::Func(func))) => func.block().unwrap().statements().count(),
+            other => panic!("Expected function, found {other:?}"),
+        };
+        assert_eq!(statements, 2);
+    }
+}
@@ -1,4 +1,5 @@
+pub mod generic;
 pub mod identifier;
 pub mod keyword;
 pub mod path;
-pub mod ty;
\ No newline at end of file
+pub mod ty;
@@ -0,0 +1,281 @@
+use guano_syntax::{consts::Punctuation, leaf, node, Child, SyntaxKind};
+
+use crate::parsing::{
+    combinators::{tag, tuple, Combinators},
+    error::Res,
+    parsers::{
+        declaration::prototype::proto_extension,
+        ignorable::{eat_ignorable, IgnorableParser},
+    },
+    ParseContext, Parser,
+};
+
+use super::{identifier::iden, path::type_path, ty::ty};
+
+/// Parse generic parameters, such as `<T, U: Comparable + Hashable>`.
+pub fn generic_params<'source>(context: &mut ParseContext<'source>) -> Res<'source, Child> {
+    let first_param =
+        tuple((iden, eat_ignorable.then(generic_bounds).optional())).map(|(name, bounds)| {
+            let mut children = vec![name];
+
+            if let Some((ws, bounds)) = bounds {
+                children.extend(ws);
+                children.push(bounds);
+            }
+
+            node(SyntaxKind::GENERIC_PARAM, children)
+        });
+    let other_params = eat_ignorable.then(generic_param).repeated();
+
+    let (l_angle, (l_ws, params, r_ws), r_angle) = tuple((
+        Punctuation::LT,
+        tuple((first_param, other_params)).optional().padded(),
+        right_angle.expected(),
+    ))
+    .parse(context)?;
+
+    let mut children = vec![l_angle];
+    children.extend(l_ws);
+
+    if let Some((first_param, other_params)) = params {
+        children.push(first_param);
+
+        for (ws, param) in other_params {
+            children.extend(ws);
+            children.push(param);
+        }
+    }
+
+    children.extend(r_ws);
+    children.push(r_angle);
+
+    Ok(node(SyntaxKind::GENERIC_PARAMS, children))
+}
+
+pub fn generic_param<'source>(context: &mut ParseContext<'source>) -> Res<'source, Child> {
+    let (com, ws, name, bounds) = tuple((
+        Punctuation::COMMA,
+        eat_ignorable,
+        iden.expected(),
+        eat_ignorable.then(generic_bounds).optional(),
+    ))
+    .parse(context)?;
+
+    let mut children = vec![com];
+    children.extend(ws);
+    children.push(name);
+
+    if let Some((ws, bounds)) = bounds {
+        children.extend(ws);
+        children.push(bounds);
+    }
+
+    Ok(node(SyntaxKind::GENERIC_PARAM, children))
+}
+
+/// Parse the protos a generic parameter is bound by, such as `: Comparable + Hashable`.
+pub fn generic_bounds<'source>(context: &mut ParseContext<'source>) -> Res<'source, Child> {
+    let (col, ws, first_bound, other_bounds) = tuple((
+        Punctuation::COLON,
+        eat_ignorable,
+        type_path
+            .map(|p| node(SyntaxKind::PROTO_EXTENSION, vec![p]))
+            .expected(),
+        eat_ignorable.then(proto_extension).repeated(),
+    ))
+    .parse(context)?;
+
+    let mut children = vec![col];
+    children.extend(ws);
+    children.push(first_bound);
+
+    for (ws, bound) in other_bounds {
+        children.extend(ws);
+        children.push(bound);
+    }
+
+    Ok(node(SyntaxKind::GENERIC_BOUNDS, children))
+}
+
+/// Parse generic arguments, such as `<string, [int]>`.
+///
+/// Unlike most delimited parsers, a missing `>` is
+/// a failure rather than an error, so that the caller
+/// can fall back to treating `<` as an operator.
+pub fn generic_args<'source>(context: &mut ParseContext<'source>) -> Res<'source, Child> {
+    let first_arg = ty.map(|ty| node(SyntaxKind::GENERIC_ARG, vec![ty]));
+    let other_args = eat_ignorable.then(generic_arg).repeated();
+
+    let (l_angle, (l_ws, (first_arg, other_args), r_ws), r_angle) = tuple((
+        Punctuation::LT,
+        tuple((first_arg, other_args)).padded(),
+        right_angle,
+    ))
+    .parse(context)?;
+
+    let mut children = vec![l_angle];
+    children.extend(l_ws);
+    children.push(first_arg);
+
+    for (ws, arg) in other_args {
+        children.extend(ws);
+        children.push(arg);
+    }
+
+    children.extend(r_ws);
+    children.push(r_angle);
+
+    Ok(node(SyntaxKind::GENERIC_ARGS, children))
+}
+
+pub fn generic_arg<'source>(context: &mut ParseContext<'source>) -> Res<'source, Child> {
+    let (com, ws, ty) = tuple((Punctuation::COMMA, eat_ignorable, ty)).parse(context)?;
+
+    let mut children = vec![com];
+    children.extend(ws);
+    children.push(ty);
+
+    Ok(node(SyntaxKind::GENERIC_ARG, children))
+}
+
+/// Parse a single `>`, even if it is the start of `>>` or `>=`,
+/// so that nested arguments like `Map<string, Box<int>>` close properly.
+pub fn right_angle<'source>(context: &mut ParseContext<'source>) -> Res<'source, Child> {
+    tag(">")
+        .map(|text| leaf(SyntaxKind::GT, text))
+        .parse(context)
+}
+
+#[cfg(test)]
+mod test {
+    use guano_common::rowan::ast::AstNode;
+    use guano_syntax::{
+        nodes::{Decl, Expr, ModuleItem, Path, Type},
+        SyntaxNode,
+    };
+
+    use crate::parsing::{parsers::expression::expr, ParseContext, Parser};
+
+    fn parse_expr(source: &str) -> Expr {
+        let mut context = ParseContext::new(source);
+        let node = expr.parse(&mut context).unwrap();
+        assert!(context.errors().is_empty(), "{:?}", context.errors());
+        assert!(context.is_eof(), "Remaining: {:?}", context.remaining());
+
+        Expr::cast(SyntaxNode::new_root(node.into_node().unwrap())).unwrap()
+    }
+
+    fn generic_args(path: &Path) -> Vec<String> {
+        path.path_segments()
+            .filter_map(|s| s.generic_args())
+            .flat_map(|a| a.types())
+            .map(|t| t.syntax().to_string())
+            .collect()
+    }
+
+    #[test]
+    fn test_generic_decls() {
+        let source = r#"
+        pub class Box<T>: Container<T> {
+            value: T;
+            cache: Map<string, Box<[T]>>;
+        }
+
+        pub proto Iterator<T>: Iterable<T> {
+            fun next -> T?;
+        }
+
+        fun map<T, U: Comparable + Hashable>(list: [T], f: fun(T) -> U) -> [U];"#;
+
+        let (context, file) = crate::parse_file(source);
+        assert!(context.errors().is_empty(), "{:?}", context.errors());
+        assert!(context.is_eof());
+
+        let items = file.items().collect::<Vec<_>>();
+        let (class, proto, func) = match &items[..] {
+            [ModuleItem::Decl(Decl::Class(class)), ModuleItem::Decl(Decl::Proto(proto)), ModuleItem::Decl(Decl::Func(func))] => {
+                (class, proto, func)
+            }
+            other => panic!("Unexpected items {other:?}"),
+        };
+
+        let names = class
+            .generics()
+            .map(|g| g.name().unwrap().as_str().to_owned())
+            .collect::<Vec<_>>();
+        assert_eq!(names, ["T"]);
+        let extends = class.class_extends().unwrap().path().unwrap();
+        assert_eq!(generic_args(&extends), ["T"]);
+
+        let field = class
+            .class_body()
+            .and_then(|b| b.class_block())
+            .and_then(|b| b.class_fields().nth(1))
+            .unwrap();
+        let path = match field.ty() {
+            Some(Type::Path(path)) => path,
+            other => panic!("Expected path type, found {other:?}"),
+        };
+        assert_eq!(generic_args(&path), ["string", "Box<[T]>"]);
+
+        assert_eq!(proto.generics().count(), 1);
+
+        let generics = func.generics().collect::<Vec<_>>();
+        assert_eq!(generics.len(), 2);
+        assert_eq!(generics[0].bounds().count(), 0);
+        let bounds = generics[1]
+            .bounds()
+            .map(|b| b.syntax().to_string())
+            .collect::<Vec<_>>();
+        assert_eq!(bounds, ["Comparable", "Hashable"]);
+    }
+
+    #[test]
+    fn test_generic_exprs() {
+        match parse_expr("a < b") {
+            Expr::BinaryExpr(_) => {}
+            other => panic!("Expected comparison, found {other:?}"),
+        }
+
+        match parse_expr("a < b && c > (d)") {
+            Expr::BinaryExpr(_) => {}
+            other => panic!("Expected comparison, found {other:?}"),
+        }
+
+        // Two comparisons, not a call to `a::<b, c>`.
+        match parse_expr("f(a < b, c > (d))") {
+            Expr::CallExpr(call) => {
+                let args = call
+                    .list_expr_items()
+                    .map(|i| matches!(i.expr(), Some(Expr::BinaryExpr(_))))
+                    .collect::<Vec<_>>();
+                assert_eq!(args, [true, true]);
+            }
+            other => panic!("Expected call, found {other:?}"),
+        }
+
+        match parse_expr("Box::<int>(5)") {
+            Expr::CallExpr(call) => match call.expr() {
+                Some(Expr::Path(path)) => assert_eq!(generic_args(&path), ["int"]),
+                other => panic!("Expected path, found {other:?}"),
+            },
+            other => panic!("Expected call, found {other:?}"),
+        }
+
+        match parse_expr("List::<Map<string, int>>::new()") {
+            Expr::CallExpr(call) => match call.expr() {
+                Some(Expr::Path(path)) => {
+                    assert_eq!(path.path_segments().count(), 2);
+                    assert_eq!(generic_args(&path), ["Map<string, int>"]);
+                }
+                other => panic!("Expected path, found {other:?}"),
+            },
+            other => panic!("Expected call, found {other:?}"),
+        }
+
+        match parse_expr("x as int < 10") {
+            Expr::BinaryExpr(_) => {}
+            other => panic!("Expected comparison, found {other:?}"),
+        }
+    }
+}
@@ -1,29 +1,21 @@
 use guano_syntax::{leaf, Child, SyntaxKind};
 
 use crate::parsing::{
-    combinators::{regex, Combinators},
     error::{Error, ErrorKind, Res},
-    ParseContext, Parser,
+    ParseContext,
 };
 
-#[inline]
-pub fn raw_iden<'source>(context: &mut ParseContext<'source>) -> Res<'source> {
-    regex(r"^[_a-zA-Z][_0-9a-zA-Z]*").parse(context)
-}
-
+/// Parse an identifier. A lone `_` is lexed
+/// as punctuation, but it is still a valid name.
 pub fn iden<'source>(context: &mut ParseContext<'source>) -> Res<'source, Child> {
-    let (iden, span) = raw_iden.spanned().parse(context)?;
-    let is_keyword = guano_syntax::consts::Keyword::ALL
-        .into_iter()
-        .map(|k| k.as_str())
-        .any(|s| s == iden);
-
-    if is_keyword {
-        return Err(Error::spanned(
-            span,
+    match context.token() {
+        Some(token) if matches!(token.kind, SyntaxKind::IDEN | SyntaxKind::UNDERSCORE) => {
+            Ok(leaf(SyntaxKind::IDEN, context.consume(token)))
+        }
+        Some(token) if token.kind.is_keyword() => Err(Error::spanned(
+            token.range,
             ErrorKind::String("Found keyword, not identifier".into()),
-        ));
+        )),
+        _ => Err(Error::expected(context.span(), "identifier")),
     }
-
-    Ok(leaf(SyntaxKind::IDEN, iden))
 }
@@ -1,28 +1,20 @@
-use guano_syntax::{
-    consts::{self, Keyword},
-    leaf, Child,
-};
+use std::borrow::Cow;
+
+use guano_syntax::{consts::Keyword, leaf, Child};
 
 use crate::parsing::{
-    combinators::Combinators,
     error::{Error, ErrorKind, Res},
     ParseContext, Parser,
 };
 
-use super::identifier::raw_iden;
-
 pub fn keyword<'source>(context: &mut ParseContext<'source>) -> Res<'source, Child> {
-    let (raw_iden, span) = raw_iden.spanned().peek().parse(context)?;
-
-    for keyword in consts::Keyword::ALL {
-        if keyword.as_str() == raw_iden {
-            *context.position_mut() += span.len();
-            return Ok(leaf(keyword.syntax_kind(), raw_iden));
+    match context.token() {
+        Some(token) if token.kind.is_keyword() => Ok(leaf(token.kind, context.consume(token))),
+        _ => {
+            let kind = ErrorKind::String("Expected keyword".into());
+            Err(Error::spanned(context.span(), kind))
         }
     }
-
-    let kind = ErrorKind::String("Expected keyword".into());
-    Err(Error::spanned(context.span(), kind))
 }
 
 impl<'source> Parser<'source> for Keyword {
@@ -30,17 +22,22 @@ impl<'source> Parser<'source> for Keyword {
     type Error = Error<'source>;
 
     fn parse(self, context: &mut ParseContext<'source>) -> Result<Self::Output, Self::Error> {
-        let (keyword, span) = keyword.spanned().peek().parse(context).map_err(|mut e| {
-            e.kind = ErrorKind::String(format!("Expected {:?}", self.as_str()).into());
-            e
-        })?;
-
-        if keyword.kind().0 == self.syntax_kind() as u16 {
-            *context.position_mut() += span.len();
-            Ok(keyword)
-        } else {
-            let kind = ErrorKind::String(format!("Expected {:?}", self.as_str()).into());
-            Err(Error::spanned(context.span(), kind))
-        }
+        context.trace_enter(|| self.name());
+
+        let result = match context.token() {
+            Some(token) if token.kind == self.syntax_kind() => {
+                Ok(leaf(token.kind, context.consume(token)))
+            }
+            _ => Err(Error::expected(context.span(), self.name())),
+        };
+
+        context.trace_exit(result.is_ok());
+
+        result
+    }
+
+    #[inline]
+    fn name(&self) -> Cow<'static, str> {
+        format!("'{}'", self.as_str()).into()
     }
 }
@@ -1,3 +1,4 @@
+use guano_common::rowan::NodeOrToken;
 use guano_syntax::{
     consts::{Keyword, Punctuation},
     node, Child, SyntaxKind,
@@ -10,7 +11,9 @@ use crate::parsing::{
     ParseContext, Parser,
 };
 
-use super::identifier::iden;
+use super::{generic::generic_args, identifier::iden};
+
+type GenericArgsFn<'source> = fn(&mut ParseContext<'source>) -> Res<'source, Child>;
 
 pub fn name<'source>(context: &mut ParseContext<'source>) -> Res<'source, Child> {
     let name = alternation((Keyword::THIS, iden)).parse(context)?;
@@ -19,24 +22,87 @@ pub fn name<'source>(context: &mut ParseContext<'source>) -> Res<'source, Child>
 }
 
 pub fn path_segment<'source>(context: &mut ParseContext<'source>) -> Res<'source, Child> {
-    let (col, ws, name) = tuple((Punctuation::COLON2, eat_ignorable, name)).parse(context)?;
-    let mut children = vec![col];
-    children.extend(ws);
-    children.push(name);
-
-    Ok(node(SyntaxKind::PATH_SEGMENT, children))
+    path_segment_with(expr_generic_args)(context)
 }
 
+/// Parse a path in expression position.
+///
+/// Generic arguments need a leading `::` here, such as `Box::<int>(1)`
+/// or `List::<int>::new`, so that a `<` after a path is always left for
+/// the pratt parser as a comparison. Otherwise `f(a < b, c > (d))` would
+/// be ambiguous with a call to `a::<b, c>`.
 pub fn path<'source>(context: &mut ParseContext<'source>) -> Res<'source, Child> {
-    let (mut first_segment, other_segments) =
-        tuple((name, eat_ignorable.then(path_segment).repeated())).parse(context)?;
-    first_segment = node(SyntaxKind::PATH_SEGMENT, vec![first_segment]);
+    path_with(expr_generic_args)(context)
+}
+
+/// Parse a path in type position, where every
+/// segment may have generic arguments.
+pub fn type_path<'source>(context: &mut ParseContext<'source>) -> Res<'source, Child> {
+    path_with(generic_args)(context)
+}
 
-    let mut children = vec![first_segment];
-    for (ws, seg) in other_segments {
+fn path_with<'source>(
+    generic_args: GenericArgsFn<'source>,
+) -> impl FnMut(&mut ParseContext<'source>) -> Res<'source, Child> + Copy {
+    move |context| {
+        let (name, args, other_segments) = tuple((
+            name,
+            eat_ignorable.then(generic_args).optional(),
+            eat_ignorable
+                .then(path_segment_with(generic_args))
+                .repeated(),
+        ))
+        .parse(context)?;
+
+        let mut first_segment = vec![name];
+        if let Some((ws, args)) = args {
+            first_segment.extend(ws);
+            first_segment.push(args);
+        }
+
+        let mut children = vec![node(SyntaxKind::PATH_SEGMENT, first_segment)];
+        for (ws, seg) in other_segments {
+            children.extend(ws);
+            children.push(seg);
+        }
+
+        Ok(node(SyntaxKind::PATH, children))
+    }
+}
+
+fn path_segment_with<'source>(
+    generic_args: GenericArgsFn<'source>,
+) -> impl FnMut(&mut ParseContext<'source>) -> Res<'source, Child> + Copy {
+    move |context| {
+        let (col, ws, name, args) = tuple((
+            Punctuation::COLON2,
+            eat_ignorable,
+            name,
+            eat_ignorable.then(generic_args).optional(),
+        ))
+        .parse(context)?;
+
+        let mut children = vec![col];
         children.extend(ws);
-        children.push(seg);
+        children.push(name);
+
+        if let Some((ws, args)) = args {
+            children.extend(ws);
+            children.push(args);
+        }
+
+        Ok(node(SyntaxKind::PATH_SEGMENT, children))
     }
+}
+
+/// Generic arguments in expression position, such as `::<int>`.
+fn expr_generic_args<'source>(context: &mut ParseContext<'source>) -> Res<'source, Child> {
+    let (col, ws, args) =
+        tuple((Punctuation::COLON2, eat_ignorable, generic_args)).parse(context)?;
+
+    let args = args.into_node().unwrap();
+    let mut prefix = vec![col];
+    prefix.extend(ws);
 
-    Ok(node(SyntaxKind::PATH, children))
+    Ok(NodeOrToken::Node(args.splice_children(0..0, prefix)))
 }
@@ -1,16 +1,22 @@
-use guano_syntax::{consts::Punctuation, node, Child, SyntaxKind};
+use guano_syntax::{
+    consts::{Keyword, Punctuation},
+    node, Child, SyntaxKind,
+};
 
 use crate::parsing::{
     combinators::{alternation, tuple, Combinators},
     error::Res,
-    parsers::ignorable::{eat_ignorable, IgnorableParser},
+    parsers::{
+        declaration::function::func_type,
+        ignorable::{eat_ignorable, IgnorableParser},
+    },
     ParseContext, Parser,
 };
 
-use super::path::path;
+use super::path::type_path;
 
 pub fn ty<'source>(context: &mut ParseContext<'source>) -> Res<'source, Child> {
-    nilable_type(context)
+    nilable_type.memoized().parse(context)
 }
 
 pub fn nilable_type<'source>(context: &mut ParseContext<'source>) -> Res<'source, Child> {
@@ -36,7 +42,7 @@ pub fn nilable_type<'source>(context: &mut ParseContext<'source>) -> Res<'source
 }
 
 pub fn primary_type<'source>(context: &mut ParseContext<'source>) -> Res<'source, Child> {
-    alternation((list_type, path)).parse(context)
+    alternation((list_type, function_type, type_path)).parse(context)
 }
 
 pub fn list_type<'source>(context: &mut ParseContext<'source>) -> Res<'source, Child> {
@@ -56,3 +62,66 @@ pub fn list_type<'source>(context: &mut ParseContext<'source>) -> Res<'source, C
 
     Ok(list)
 }
+
+/// Parses a function type, such as `fun(int, string) -> bool`.
+pub fn function_type<'source>(context: &mut ParseContext<'source>) -> Res<'source, Child> {
+    let (kw, params, ret) = tuple((
+        Keyword::FUN,
+        eat_ignorable.then(function_type_params).optional(),
+        eat_ignorable.then(func_type).optional(),
+    ))
+    .parse(context)?;
+
+    let mut children = vec![kw];
+
+    if let Some((ws, params)) = params {
+        children.extend(ws);
+        children.push(params);
+    }
+
+    if let Some((ws, ret)) = ret {
+        children.extend(ws);
+        children.push(ret);
+    }
+
+    Ok(node(SyntaxKind::FUNCTION_TYPE, children))
+}
+
+pub fn function_type_params<'source>(context: &mut ParseContext<'source>) -> Res<'source, Child> {
+    let first_param = ty.map(|ty| node(SyntaxKind::FUNCTION_TYPE_PARAM, vec![ty]));
+    let other_params = eat_ignorable.then(function_type_param).repeated();
+
+    let (l_paren, (l_ws, params, r_ws), r_paren) = tuple((
+        Punctuation::LEFT_PAREN,
+        tuple((first_param, other_params)).optional().padded(),
+        Punctuation::RIGHT_PAREN.expected(),
+    ))
+    .parse(context)?;
+
+    let mut children = vec![l_paren];
+    children.extend(l_ws);
+
+    if let Some((first_param, other_params)) = params {
+        children.push(first_param);
+
+        for (ws, param) in other_params {
+            children.extend(ws);
+            children.push(param);
+        }
+    }
+
+    children.extend(r_ws);
+    children.push(r_paren);
+
+    Ok(node(SyntaxKind::FUNCTION_TYPE_PARAMS, children))
+}
+
+pub fn function_type_param<'source>(context: &mut ParseContext<'source>) -> Res<'source, Child> {
+    let (com, ws, ty) = tuple((Punctuation::COMMA, eat_ignorable, ty.expected())).parse(context)?;
+
+    let mut children = vec![com];
+    children.extend(ws);
+    children.push(ty);
+
+    Ok(node(SyntaxKind::FUNCTION_TYPE_PARAM, children))
+}
@@ -0,0 +1,296 @@
+use std::{
+    borrow::Cow,
+    fmt::{self, Write},
+};
+
+use guano_common::rowan::{TextRange, TextSize};
+
+/// Whether a traced parser succeeded.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Outcome {
+    Success,
+    Failure,
+}
+
+/// Something that happened while parsing.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TraceEvent<'source> {
+    /// A parser was started at a position.
+    Enter {
+        name: Cow<'static, str>,
+        position: TextSize,
+    },
+    /// A parser returned, having consumed `text`.
+    Exit {
+        name: Cow<'static, str>,
+        range: TextRange,
+        text: &'source str,
+        outcome: Outcome,
+    },
+}
+
+/// The events recorded while parsing with the `trace` feature enabled.
+///
+/// Only parsers that are functions, named parsers, and the token
+/// parsers are recorded, the combinators in between are left out.
+#[derive(Debug, Clone, Default)]
+pub struct Trace<'source> {
+    events: Vec<TraceEvent<'source>>,
+    stack: Vec<(Cow<'static, str>, TextSize)>,
+}
+
+impl<'source> Trace<'source> {
+    /// Every event, in the order they happened.
+    #[inline]
+    pub fn events(&self) -> &[TraceEvent<'source>] {
+        &self.events
+    }
+
+    /// Forget all of the recorded events.
+    #[inline]
+    pub fn clear(&mut self) {
+        self.events.clear();
+        self.stack.clear();
+    }
+
+    pub(crate) fn enter(&mut self, name: Cow<'static, str>, position: TextSize) {
+        self.stack.push((name.clone(), position));
+        self.events.push(TraceEvent::Enter { name, position });
+    }
+
+    pub(crate) fn exit(&mut self, source: &'source str, position: TextSize, outcome: Outcome) {
+        let Some((name, start)) = self.stack.pop() else {
+            return;
+        };
+
+        // Failed parsers aren't guaranteed to leave the position after their start.
+        let range = TextRange::new(start, position.max(start));
+
+        self.events.push(TraceEvent::Exit {
+            name,
+            range,
+            text: &source[range],
+            outcome,
+        });
+    }
+
+    /// Group the events into a tree of calls.
+    pub fn calls(&self) -> Vec<TraceCall<'source>> {
+        let mut stack = vec![TraceCall::root()];
+
+        for event in &self.events {
+            match event {
+                TraceEvent::Enter { name, position } => stack.push(TraceCall {
+                    name: name.clone(),
+                    range: TextRange::empty(*position),
+                    text: "",
+                    outcome: None,
+                    children: vec![],
+                }),
+                TraceEvent::Exit {
+                    range,
+                    text,
+                    outcome,
+                    ..
+                } => {
+                    if stack.len() == 1 {
+                        continue;
+                    }
+
+                    let mut call = stack.pop().unwrap();
+                    call.range = *range;
+                    call.text = text;
+                    call.outcome = Some(*outcome);
+
+                    stack.last_mut().unwrap().children.push(call);
+                }
+            }
+        }
+
+        // Close the calls that never returned.
+        while stack.len() > 1 {
+            let call = stack.pop().unwrap();
+            stack.last_mut().unwrap().children.push(call);
+        }
+
+        stack.pop().unwrap().children
+    }
+
+    /// Render the trace as an indented tree, one call per line.
+    pub fn to_tree(&self) -> String {
+        let mut out = String::new();
+
+        for call in self.calls() {
+            call.write_tree(&mut out, 0).unwrap();
+        }
+
+        out
+    }
+
+    /// Render the trace as a JSON array of calls.
+    pub fn to_json(&self) -> String {
+        let mut out = String::new();
+        write_json_list(&mut out, &self.calls()).unwrap();
+        out
+    }
+}
+
+/// A single traced parser, along with the parsers it called.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TraceCall<'source> {
+    pub name: Cow<'static, str>,
+    pub range: TextRange,
+    pub text: &'source str,
+    /// `None` if the parser never returned.
+    pub outcome: Option<Outcome>,
+    pub children: Vec<TraceCall<'source>>,
+}
+
+impl<'source> TraceCall<'source> {
+    fn root() -> Self {
+        Self {
+            name: "".into(),
+            range: TextRange::default(),
+            text: "",
+            outcome: None,
+            children: vec![],
+        }
+    }
+
+    fn write_tree(&self, out: &mut String, depth: usize) -> fmt::Result {
+        let start = u32::from(self.range.start());
+        let end = u32::from(self.range.end());
+        let indent = "  ".repeat(depth);
+
+        match self.outcome {
+            Some(Outcome::Success) => writeln!(
+                out,
+                "{indent}{} {start}..{end} ok {:?}",
+                self.name, self.text
+            )?,
+            Some(Outcome::Failure) => writeln!(out, "{indent}{} {start}..{end} failed", self.name)?,
+            None => writeln!(out, "{indent}{} {start}.. unfinished", self.name)?,
+        }
+
+        for child in &self.children {
+            child.write_tree(out, depth + 1)?;
+        }
+
+        Ok(())
+    }
+
+    fn write_json(&self, out: &mut String) -> fmt::Result {
+        let outcome = match self.outcome {
+            Some(Outcome::Success) => "\"success\"",
+            Some(Outcome::Failure) => "\"failure\"",
+            None => "null",
+        };
+
+        out.push_str("{\"name\":");
+        write_json_str(out, &self.name)?;
+        write!(
+            out,
+            ",\"start\":{},\"end\":{},\"outcome\":{outcome},\"text\":",
+            u32::from(self.range.start()),
+            u32::from(self.range.end()),
+        )?;
+        write_json_str(out, self.text)?;
+        out.push_str(",\"children\":");
+        write_json_list(out, &self.children)?;
+        out.push('}');
+
+        Ok(())
+    }
+}
+
+fn write_json_list(out: &mut String, calls: &[TraceCall]) -> fmt::Result {
+    out.push('[');
+
+    for (i, call) in calls.iter().enumerate() {
+        if i > 0 {
+            out.push(',');
+        }
+
+        call.write_json(out)?;
+    }
+
+    out.push(']');
+
+    Ok(())
+}
+
+fn write_json_str(out: &mut String, text: &str) -> fmt::Result {
+    out.push('"');
+
+    for ch in text.chars() {
+        match ch {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            ch if ch.is_control() => write!(out, "\\u{:04x}", ch as u32)?,
+            ch => out.push(ch),
+        }
+    }
+
+    out.push('"');
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use guano_syntax::consts::Punctuation;
+
+    use crate::parsing::{
+        combinators::Combinators, parsers::declaration::decl, ParseContext, Parser,
+    };
+
+    use super::{Outcome, TraceEvent};
+
+    #[test]
+    fn test_trace() {
+        let mut context = ParseContext::new("let a = 1;");
+        decl.parse(&mut context).unwrap();
+
+        let trace = context.trace();
+        let events = trace.events();
+
+        assert!(matches!(
+            events.first(),
+            Some(TraceEvent::Enter { name, .. }) if name.ends_with("::decl")
+        ));
+        assert!(matches!(
+            events.last(),
+            Some(TraceEvent::Exit { name, text: "let a = 1;", outcome: Outcome::Success, .. })
+                if name.ends_with("::decl")
+        ));
+
+        let enters = events
+            .iter()
+            .filter(|e| matches!(e, TraceEvent::Enter { .. }))
+            .count();
+        assert_eq!(enters * 2, events.len());
+
+        let tree = trace.to_tree();
+        assert!(tree.contains("\n  "), "{tree}");
+        assert!(tree.contains("'=' 6..7 ok \"=\""), "{tree}");
+
+        let json = trace.to_json();
+        assert!(json.starts_with("[{\"name\":"), "{json}");
+        assert!(json.contains("\"text\":\"let a = 1;\""), "{json}");
+    }
+
+    #[test]
+    fn test_trace_failure() {
+        let mut context = ParseContext::new("a");
+        Punctuation::SEMICOLON
+            .named("semicolon")
+            .parse(&mut context)
+            .unwrap_err();
+
+        let tree = context.trace().to_tree();
+        assert_eq!(tree, "semicolon 0..0 failed\n  ';' 0..0 failed\n");
+    }
+}
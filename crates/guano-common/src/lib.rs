@@ -38,6 +38,9 @@ pub extern crate regex;
 pub extern crate rowan;
 pub extern crate serde;
 
+/// Files, and positions within them.
+pub mod source_map;
+
 pub mod sync {
     pub type Map<K, V> = super::dashmap::DashMap<K, V, ahash::RandomState>;
 }
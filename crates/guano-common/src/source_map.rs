@@ -0,0 +1,324 @@
+use std::ops::Index;
+
+use rowan::{TextRange, TextSize};
+
+/// Identifies a file registered in a [SourceMap].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct FileId(u32);
+
+impl FileId {
+    #[inline]
+    pub const fn index(self) -> usize {
+        self.0 as usize
+    }
+}
+
+/// A range of text in a specific file.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct FileSpan {
+    pub file: FileId,
+    pub range: TextRange,
+}
+
+impl FileSpan {
+    #[inline]
+    pub const fn new(file: FileId, range: TextRange) -> Self {
+        Self { file, range }
+    }
+
+    #[inline]
+    pub fn start(self) -> TextSize {
+        self.range.start()
+    }
+
+    #[inline]
+    pub fn end(self) -> TextSize {
+        self.range.end()
+    }
+}
+
+/// A zero-based line and column.
+///
+/// Whether the column counts bytes or UTF-16 code units
+/// depends on the method that produced it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct LineCol {
+    pub line: u32,
+    pub col: u32,
+}
+
+impl LineCol {
+    #[inline]
+    pub const fn new(line: u32, col: u32) -> Self {
+        Self { line, col }
+    }
+}
+
+/// A file's name and text, along with where each line starts.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SourceFile {
+    name: String,
+    text: String,
+    line_starts: Vec<TextSize>,
+}
+
+impl SourceFile {
+    pub fn new(name: impl Into<String>, text: impl Into<String>) -> Self {
+        let text = text.into();
+        let line_starts = std::iter::once(0)
+            .chain(text.match_indices('\n').map(|(i, _)| i + 1))
+            .map(|i| TextSize::from(i as u32))
+            .collect();
+
+        Self {
+            name: name.into(),
+            text,
+            line_starts,
+        }
+    }
+
+    #[inline]
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    #[inline]
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+
+    #[inline]
+    pub fn len(&self) -> TextSize {
+        TextSize::of(&*self.text)
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.text.is_empty()
+    }
+
+    /// The number of lines, a trailing newline starts an empty last line.
+    #[inline]
+    pub fn line_count(&self) -> usize {
+        self.line_starts.len()
+    }
+
+    /// Move an offset back inside the text and onto a character boundary.
+    pub fn clamp(&self, offset: TextSize) -> TextSize {
+        let mut offset = offset.min(self.len());
+
+        while !self.text.is_char_boundary(offset.into()) {
+            offset -= TextSize::from(1);
+        }
+
+        offset
+    }
+
+    /// The zero-based line that contains an offset.
+    #[inline]
+    pub fn line_index(&self, offset: TextSize) -> usize {
+        self.line_starts.partition_point(|&start| start <= offset) - 1
+    }
+
+    /// The range of a line, excluding its line ending.
+    pub fn line_range(&self, line: usize) -> Option<TextRange> {
+        let start = *self.line_starts.get(line)?;
+        let end = self
+            .line_starts
+            .get(line + 1)
+            .copied()
+            .unwrap_or_else(|| self.len());
+        let text = self.text[TextRange::new(start, end)].trim_end_matches(['\n', '\r']);
+
+        Some(TextRange::at(start, TextSize::of(text)))
+    }
+
+    /// The range of a line, including its line ending but not the `\n`,
+    /// so that the end of every line can be addressed.
+    fn line_bounds(&self, line: usize) -> Option<TextRange> {
+        let start = *self.line_starts.get(line)?;
+        let end = match self.line_starts.get(line + 1) {
+            Some(next) => *next - TextSize::from(1),
+            None => self.len(),
+        };
+
+        Some(TextRange::new(start, end))
+    }
+
+    /// The line and byte column of an offset.
+    pub fn line_col(&self, offset: TextSize) -> LineCol {
+        let offset = self.clamp(offset);
+        let line = self.line_index(offset);
+        let col = offset - self.line_starts[line];
+
+        LineCol::new(line as u32, col.into())
+    }
+
+    /// The line and UTF-16 column of an offset.
+    pub fn line_col_utf16(&self, offset: TextSize) -> LineCol {
+        let offset = self.clamp(offset);
+        let line = self.line_index(offset);
+        let before = &self.text[TextRange::new(self.line_starts[line], offset)];
+        let col = before.chars().map(char::len_utf16).sum::<usize>();
+
+        LineCol::new(line as u32, col as u32)
+    }
+
+    /// The offset of a line and byte column, if it's inside
+    /// the line and on a character boundary.
+    pub fn offset(&self, line_col: LineCol) -> Option<TextSize> {
+        let range = self.line_bounds(line_col.line as usize)?;
+        let offset = range.start() + TextSize::from(line_col.col);
+
+        (offset <= range.end() && self.text.is_char_boundary(offset.into())).then_some(offset)
+    }
+
+    /// The offset of a line and UTF-16 column, if it's inside
+    /// the line and not in the middle of a character.
+    pub fn offset_utf16(&self, line_col: LineCol) -> Option<TextSize> {
+        let range = self.line_bounds(line_col.line as usize)?;
+        let mut col = line_col.col as usize;
+        let mut offset = range.start();
+
+        for ch in self.text[range].chars() {
+            if col == 0 {
+                break;
+            }
+
+            col = col.checked_sub(ch.len_utf16())?;
+            offset += TextSize::of(ch);
+        }
+
+        (col == 0).then_some(offset)
+    }
+}
+
+/// Every file that has been loaded, addressed by [FileId].
+#[derive(Debug, Clone, Default)]
+pub struct SourceMap {
+    files: Vec<SourceFile>,
+}
+
+impl SourceMap {
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Register a file, returning its new id.
+    pub fn add(&mut self, name: impl Into<String>, text: impl Into<String>) -> FileId {
+        let id = FileId(self.files.len() as u32);
+        self.files.push(SourceFile::new(name, text));
+
+        id
+    }
+
+    #[inline]
+    pub fn get(&self, file: FileId) -> Option<&SourceFile> {
+        self.files.get(file.index())
+    }
+
+    /// Every registered file, in the order they were added.
+    pub fn files(&self) -> impl Iterator<Item = (FileId, &SourceFile)> {
+        self.files
+            .iter()
+            .enumerate()
+            .map(|(i, file)| (FileId(i as u32), file))
+    }
+
+    /// The text covered by a span.
+    #[inline]
+    pub fn text(&self, span: FileSpan) -> &str {
+        &self[span.file].text()[span.range]
+    }
+
+    /// The line and byte column of the start of a span.
+    #[inline]
+    pub fn line_col(&self, span: FileSpan) -> LineCol {
+        self[span.file].line_col(span.start())
+    }
+
+    /// The line and UTF-16 column of the start of a span.
+    #[inline]
+    pub fn line_col_utf16(&self, span: FileSpan) -> LineCol {
+        self[span.file].line_col_utf16(span.start())
+    }
+}
+
+impl Index<FileId> for SourceMap {
+    type Output = SourceFile;
+
+    #[inline]
+    fn index(&self, file: FileId) -> &SourceFile {
+        &self.files[file.index()]
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use rowan::{TextRange, TextSize};
+
+    use super::{FileSpan, LineCol, SourceMap};
+
+    #[test]
+    fn test_line_col() {
+        let mut map = SourceMap::new();
+        let main = map.add("main.guano", "let a = 1;\r\nlet é = \"😀\";\n");
+        let other = map.add("other.guano", "");
+
+        assert_eq!(map[main].name(), "main.guano");
+        assert_eq!(map[main].line_count(), 3);
+        assert_eq!(map[other].line_count(), 1);
+        assert_eq!(map.files().count(), 2);
+
+        // The `=` after `é`, which is two bytes but one UTF-16 unit.
+        let eq = TextSize::from(12 + 7);
+        assert_eq!(map[main].line_col(eq), LineCol::new(1, 7));
+        assert_eq!(map[main].line_col_utf16(eq), LineCol::new(1, 6));
+
+        // The `"` after the emoji, which is four bytes and two UTF-16 units.
+        let quote = TextSize::from(12 + 14);
+        assert_eq!(map[main].line_col(quote), LineCol::new(1, 14));
+        assert_eq!(map[main].line_col_utf16(quote), LineCol::new(1, 11));
+
+        let span = FileSpan::new(main, TextRange::at(quote, 1.into()));
+        assert_eq!(map.text(span), "\"");
+        assert_eq!(map.line_col(span), LineCol::new(1, 14));
+
+        assert_eq!(
+            map[main].line_range(0),
+            Some(TextRange::new(0.into(), 10.into()))
+        );
+        assert_eq!(map[main].line_range(2), Some(TextRange::empty(29.into())));
+        assert_eq!(map[main].line_range(3), None);
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let mut map = SourceMap::new();
+        let file = map.add("main.guano", "a\n\tπ 😀 b\r\n\nend");
+        let file = &map[file];
+
+        let mut offset = TextSize::from(0);
+        for ch in file.text().chars() {
+            let utf8 = file.line_col(offset);
+            let utf16 = file.line_col_utf16(offset);
+
+            assert_eq!(file.offset(utf8), Some(offset));
+            assert_eq!(file.offset_utf16(utf16), Some(offset));
+
+            offset += TextSize::of(ch);
+        }
+
+        // Inside a character, past the end of a line, and past the last line.
+        assert_eq!(file.offset(LineCol::new(1, 2)), None);
+        assert_eq!(file.offset_utf16(LineCol::new(1, 4)), None);
+        assert_eq!(file.offset(LineCol::new(2, 1)), None);
+        assert_eq!(file.offset(LineCol::new(4, 0)), None);
+
+        assert_eq!(file.line_col(1000.into()), LineCol::new(3, 3));
+        assert_eq!(file.line_col(3.into()), LineCol::new(1, 1));
+        // Offsets inside a character are moved back to its start.
+        assert_eq!(file.line_col(4.into()), LineCol::new(1, 1));
+    }
+}
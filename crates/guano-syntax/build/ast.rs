@@ -52,7 +52,14 @@ impl AstNode {
             .map(|f| (f.is_token(), f))
             .into_group_map();
 
-        let mut fields = field_map.remove(&true).unwrap_or_else(Vec::new);
+        // Tokens that appear more than once, like the quotes around
+        // a string, only get a single accessor for the first one.
+        let mut fields = field_map
+            .remove(&true)
+            .unwrap_or_else(Vec::new)
+            .into_iter()
+            .unique_by(|f| f.method_name())
+            .collect_vec();
 
         if let Some(remaining) = field_map.remove(&false) {
             let field_map = remaining
@@ -220,10 +227,6 @@ impl AstNode {
     }
 
     fn enum_token_stream(name: &str, variants: &[String], _traits: &[String]) -> TokenStream {
-        let kinds = variants
-            .into_iter()
-            .map(|v| format_ident!("{}", v.to_shouty_snake_case()))
-            .collect_vec();
         let variants = variants
             .into_iter()
             .map(|v| format_ident!("{v}"))
@@ -232,6 +235,9 @@ impl AstNode {
         let enum_variant = format_ident!("{}", name.to_shouty_snake_case());
         let name = format_ident!("{name}");
 
+        // Variants are checked through their own `can_cast`, so that
+        // enums nested in other enums (e.g. `Decl` in `ModuleItem`)
+        // accept the kinds of their variants as well.
         quote! {
             #[derive(Debug, Clone, PartialEq, Eq, Hash)]
             #[doc = #doc]
@@ -242,19 +248,24 @@ impl AstNode {
             impl ::guano_common::rowan::ast::AstNode for #name {
                 type Language = crate::Lang;
                 fn can_cast(kind: crate::SyntaxKind) -> bool {
-                    match kind {
-                        crate::SyntaxKind::#enum_variant => true,
-                        #(crate::SyntaxKind::#kinds => true,)*
-                        _ => false,
-                    }
+                    kind == crate::SyntaxKind::#enum_variant
+                        #(|| <#variants as ::guano_common::rowan::ast::AstNode>::can_cast(kind))*
                 }
 
                 fn cast(syntax: crate::SyntaxNode) -> Option<Self> {
-                    Some(match syntax.kind() {
-                        crate::SyntaxKind::#enum_variant => return Self::cast(syntax.first_child()?),
-                        #(crate::SyntaxKind::#kinds => #name::#variants(#variants::cast(syntax).unwrap()),)*
-                        _ => return None
-                    })
+                    let kind = syntax.kind();
+
+                    if kind == crate::SyntaxKind::#enum_variant {
+                        return Self::cast(syntax.first_child()?);
+                    }
+
+                    #(
+                        if <#variants as ::guano_common::rowan::ast::AstNode>::can_cast(kind) {
+                            return <#variants as ::guano_common::rowan::ast::AstNode>::cast(syntax).map(#name::#variants);
+                        }
+                    )*
+
+                    None
                 }
 
                 fn syntax(&self) -> &crate::SyntaxNode {
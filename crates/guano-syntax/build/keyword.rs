@@ -22,11 +22,13 @@ pub enum Keyword {
     Pub,
     Proto,
     Class,
+    Enum,
     On,
     Module,
     Try,
     Catch,
     Throw,
+    Match,
     As,
     Import,
     Is,
@@ -8,6 +8,7 @@ pub enum Literal {
     Integer,
     Float,
     String,
+    StringFragment,
     Char,
 }
 
@@ -33,6 +34,7 @@ impl Literal {
             Literal::Integer => "Integer Literal",
             Literal::Float => "Float Literal",
             Literal::String => "String Literal",
+            Literal::StringFragment => "String Fragment Literal",
             Literal::Char => "Character Literal",
         }
     }
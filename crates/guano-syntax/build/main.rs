@@ -19,6 +19,7 @@ mod keyword;
 mod literal;
 mod punctuation;
 mod token;
+mod visit;
 
 fn main() {
     // rerun_if_changed(concat!(env!("CARGO_MANIFEST_DIR"), "/guano.ungram"));
@@ -42,6 +43,12 @@ fn main() {
     let mut nodes_file = open_file("nodes");
     write_nodes(&mut nodes_file, &grammar);
 
+    let mut visit_file = open_file("visit");
+    write_visit(&mut visit_file, &grammar);
+
+    let mut rewrite_file = open_file("rewrite");
+    write_rewrite(&mut rewrite_file, &grammar);
+
     let mut parser_file = open_file("consts");
     write_consts(&mut parser_file);
 }
@@ -155,6 +162,18 @@ fn write_nodes(file: &mut File, grammar: &Grammar) {
     write_source(file, source);
 }
 
+fn write_visit(file: &mut File, grammar: &Grammar) {
+    let nodes = AstNode::handle(grammar);
+
+    write_source(file, visit::visitor_token_stream(&nodes));
+}
+
+fn write_rewrite(file: &mut File, grammar: &Grammar) {
+    let nodes = AstNode::handle(grammar);
+
+    write_source(file, visit::rewriter_token_stream(&nodes));
+}
+
 fn write_consts(file: &mut File) {
     let kw_consts = Keyword::consts();
     let punct_consts = Punctuation::consts();
@@ -53,6 +53,11 @@ pub enum Punctuation {
     Lt2Eq,
     Gt2Eq,
     Ques,
+    FatArrow,
+    Underscore,
+    Quote,
+    Dot2,
+    Dot2Eq,
 }
 
 impl Punctuation {
@@ -159,6 +164,22 @@ impl Punctuation {
             repr_match_arms.push(repr_match_arm);
         }
 
+        // Longer marks come first, so that `<<=` wins over `<<` and `<`.
+        let longest_match_arms = Punctuation::reprs()
+            .sorted_by_key(|p| std::cmp::Reverse(p.1.len()))
+            .map(|(p, repr)| {
+                let name = format_ident!("{}", p.as_ref().to_shouty_snake_case());
+                let bytes = repr.chars().map(|c| {
+                    format!("b{c:?}")
+                        .parse::<TokenStream>()
+                        .expect("Punctuation should be valid byte literals")
+                });
+
+                quote! {
+                    [#(#bytes,)* ..] => Punctuation::#name,
+                }
+            });
+
         quote! {
             #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
             #[doc = "Punctuation marks"]
@@ -176,6 +197,14 @@ impl Punctuation {
                     }
                 }
 
+                #[doc = "The longest punctuation mark at the start of `input`."]
+                pub const fn longest_match(input: &str) -> Option<Punctuation> {
+                    Some(match input.as_bytes() {
+                        #(#longest_match_arms)*
+                        _ => return None,
+                    })
+                }
+
                 pub const fn syntax_kind(&self) -> crate::SyntaxKind {
                     match self {
                         #(Punctuation::#names => crate::SyntaxKind::#names,)*
@@ -240,6 +269,11 @@ impl Punctuation {
             Punctuation::StarEq => "*=",
             Punctuation::PercentEq => "%=",
             Punctuation::Ques => "?",
+            Punctuation::FatArrow => "=>",
+            Punctuation::Underscore => "_",
+            Punctuation::Quote => "\"",
+            Punctuation::Dot2 => "..",
+            Punctuation::Dot2Eq => "..=",
         }
     }
 }
@@ -6,6 +6,8 @@ use strum::{AsRefStr, EnumIter, EnumVariantNames, IntoEnumIterator, VariantNames
 #[derive(Debug, Clone, Copy, EnumVariantNames, AsRefStr, EnumIter)]
 pub enum Token {
     Comment,
+    DocComment,
+    InnerDocComment,
     Iden,
     Whitespace,
     Error,
@@ -35,6 +37,8 @@ impl Token {
     pub fn doc(&self) -> &'static str {
         match self {
             Token::Comment => "Comment Token",
+            Token::DocComment => "Outer Doc Comment Token, like `///` or `/**`",
+            Token::InnerDocComment => "Inner Doc Comment Token, like `//!` or `/*!`",
             Token::Iden => "Identifier Token",
             Token::Whitespace => "Whitespace Token",
             Token::Error => "Error Token",
@@ -0,0 +1,187 @@
+use heck::ToSnakeCase;
+use itertools::Itertools;
+use proc_macro2::{Ident, TokenStream};
+use quote::{format_ident, quote};
+
+use crate::ast::{AstNode, Field};
+
+/// The node types a struct's children can be, with the structs
+/// first so that a child is visited through the most specific type
+/// (e.g. the `Block` of an `IfExpr` rather than its `Expr` condition).
+fn child_types<'a>(nodes: &[AstNode], fields: &'a [Field]) -> Vec<&'a str> {
+    let is_enum = |ty: &str| {
+        nodes
+            .iter()
+            .any(|n| matches!(n, AstNode::Enum { name, .. } if name == ty))
+    };
+
+    fields
+        .iter()
+        .filter_map(|f| match f {
+            Field::Node { ty, .. } => Some(&**ty),
+            Field::Token { .. } => None,
+        })
+        .unique()
+        .sorted_by_key(|ty| (is_enum(ty), *ty))
+        .collect()
+}
+
+fn snake(name: &str) -> String {
+    name.to_snake_case()
+}
+
+fn method(prefix: &str, name: &str) -> Ident {
+    format_ident!("{prefix}_{}", snake(name))
+}
+
+pub fn visitor_token_stream(nodes: &[AstNode]) -> TokenStream {
+    let mut methods = vec![];
+    let mut walks = vec![];
+
+    for node in nodes {
+        let (name, body) = match node {
+            AstNode::Struct { name, fields, .. } => {
+                let types = child_types(nodes, fields);
+                let body = if types.is_empty() {
+                    quote! { let _ = (visitor, node); }
+                } else {
+                    let tys = types.iter().map(|ty| format_ident!("{ty}"));
+                    let visits = types.iter().map(|ty| method("visit", ty));
+
+                    quote! {
+                        for child in node.syntax().children() {
+                            #(
+                                if let Some(node) = <#tys as AstNode>::cast(child.clone()) {
+                                    visitor.#visits(&node);
+                                    continue;
+                                }
+                            )*
+                        }
+                    }
+                };
+
+                (name, body)
+            }
+            AstNode::Enum { name, variants, .. } => {
+                let ty = format_ident!("{name}");
+                let vars = variants.iter().map(|v| format_ident!("{v}"));
+                let visits = variants.iter().map(|v| method("visit", v));
+
+                let body = quote! {
+                    match node {
+                        #(#ty::#vars(node) => visitor.#visits(node),)*
+                    }
+                };
+
+                (name, body)
+            }
+        };
+
+        let ty = format_ident!("{name}");
+        let visit = method("visit", name);
+        let walk = method("walk", name);
+
+        methods.push(quote! {
+            #[inline]
+            fn #visit(&mut self, node: &#ty) {
+                #walk(self, node)
+            }
+        });
+        walks.push(quote! {
+            pub fn #walk<V: Visitor + ?Sized>(visitor: &mut V, node: &#ty) {
+                #body
+            }
+        });
+    }
+
+    quote! {
+        #[doc = "Walks a syntax tree in source order, with a method per node type."]
+        #[doc = ""]
+        #[doc = "Every method visits the node's children by default, overriding one"]
+        #[doc = "and calling the matching `walk_*` function keeps the recursion going."]
+        pub trait Visitor {
+            #(#methods)*
+        }
+
+        #(#walks)*
+    }
+}
+
+pub fn rewriter_token_stream(nodes: &[AstNode]) -> TokenStream {
+    let mut methods = vec![];
+    let mut walks = vec![];
+
+    for node in nodes {
+        let (name, body) = match node {
+            AstNode::Struct { name, fields, .. } => {
+                let types = child_types(nodes, fields);
+                let body = if types.is_empty() {
+                    quote! {
+                        let _ = rewriter;
+                        node
+                    }
+                } else {
+                    let rewrites = types.iter().map(|ty| method("rewrite", ty));
+
+                    quote! {
+                        rebuild(node, |child| {
+                            #(
+                                if let Some(done) = rewrite_child(child, |node| rewriter.#rewrites(node)) {
+                                    return Some(done);
+                                }
+                            )*
+
+                            None
+                        })
+                    }
+                };
+
+                (name, body)
+            }
+            AstNode::Enum { name, variants, .. } => {
+                let ty = format_ident!("{name}");
+                let vars = variants.iter().map(|v| format_ident!("{v}"));
+                let rewrites = variants.iter().map(|v| method("rewrite", v));
+
+                let body = quote! {
+                    match node {
+                        #(#ty::#vars(node) => rewriter.#rewrites(node).into(),)*
+                    }
+                };
+
+                (name, body)
+            }
+        };
+
+        let ty = format_ident!("{name}");
+        let rewrite = method("rewrite", name);
+        let walk = method("walk", name);
+
+        methods.push(quote! {
+            #[inline]
+            fn #rewrite(&mut self, node: #ty) -> #ty {
+                #walk(self, node)
+            }
+        });
+        walks.push(quote! {
+            pub fn #walk<R: Rewriter + ?Sized>(rewriter: &mut R, node: #ty) -> #ty {
+                #body
+            }
+        });
+    }
+
+    quote! {
+        #[doc = "Rewrites a syntax tree, with a method per node type that returns"]
+        #[doc = "the node to put in place of the one it was given."]
+        #[doc = ""]
+        #[doc = "Every method rewrites the node's children by default, overriding one"]
+        #[doc = "and calling the matching `walk_*` function keeps the recursion going."]
+        #[doc = "Returning a node of another kind is only possible from the methods"]
+        #[doc = "of enums, like [Rewriter::rewrite_expr]."]
+        pub trait Rewriter {
+            #(#methods)*
+        }
+
+        #(#walks)*
+    }
+}
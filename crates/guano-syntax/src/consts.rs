@@ -1 +1,49 @@
 include!(concat!(env!("OUT_DIR"), "/generated_consts.rs"));
+
+#[cfg(test)]
+mod test {
+    use super::Punctuation;
+
+    /// Find the longest mark by trying every one of them.
+    fn brute_force(input: &str) -> Option<Punctuation> {
+        Punctuation::ALL
+            .iter()
+            .copied()
+            .filter(|p| input.starts_with(p.as_str()))
+            .max_by_key(|p| p.as_str().len())
+    }
+
+    #[test]
+    fn test_longest_match() {
+        let mut alphabet = Punctuation::ALL
+            .iter()
+            .flat_map(|p| p.as_str().chars())
+            .collect::<Vec<_>>();
+        alphabet.sort_unstable();
+        alphabet.dedup();
+        alphabet.push('a');
+
+        // Every string of up to three characters, which covers all
+        // of the marks followed by anything they could run into.
+        let mut inputs = vec![String::new()];
+        for _ in 0..3 {
+            inputs = inputs
+                .iter()
+                .flat_map(|input| alphabet.iter().map(move |c| format!("{input}{c}")))
+                .chain(inputs.iter().cloned())
+                .collect();
+        }
+
+        for input in &inputs {
+            assert_eq!(
+                Punctuation::longest_match(input),
+                brute_force(input),
+                "{input:?}"
+            );
+        }
+
+        for mark in Punctuation::ALL {
+            assert_eq!(Punctuation::longest_match(mark.as_str()), Some(*mark));
+        }
+    }
+}
@@ -0,0 +1,228 @@
+use guano_common::rowan::{ast::AstNode, GreenNode, NodeOrToken};
+
+use crate::{
+    consts::Punctuation,
+    leaf,
+    make::{self, INDENT},
+    nodes::*,
+    Lang, SyntaxElement, SyntaxKind, SyntaxNode, SyntaxToken,
+};
+
+// Every function here edits a tree in place, which only works on trees
+// made mutable with `clone_for_update`. Inserted nodes are copied with
+// [detached], so nodes from other trees or from [make] can be used as is.
+
+/// A mutable copy of `node` that isn't part of any tree.
+pub fn detached<N: AstNode<Language = Lang>>(node: &N) -> N {
+    N::cast(node.syntax().clone_subtree().clone_for_update()).unwrap()
+}
+
+/// A mutable token that isn't part of any tree.
+pub fn token(kind: SyntaxKind, text: &str) -> SyntaxToken {
+    let green = GreenNode::new(SyntaxKind::ERROR.into(), [leaf(kind, text)]);
+    let token = SyntaxNode::new_root(green)
+        .clone_for_update()
+        .first_token()
+        .unwrap();
+    token.detach();
+
+    token
+}
+
+#[inline]
+fn ws(text: &str) -> SyntaxElement {
+    token(SyntaxKind::WHITESPACE, text).into()
+}
+
+/// Insert `elements` into `parent` before the child at `index`.
+#[inline]
+pub fn insert(parent: &SyntaxNode, index: usize, elements: Vec<SyntaxElement>) {
+    parent.splice_children(index..index, elements);
+}
+
+/// Put `new` in place of `old`.
+pub fn replace(old: impl Into<SyntaxElement>, new: impl Into<SyntaxElement>) {
+    let old = old.into();
+    let parent = old.parent().expect("can't replace the root of a tree");
+    let index = old.index();
+
+    parent.splice_children(index..index + 1, vec![new.into()]);
+}
+
+/// Remove an element from its tree.
+#[inline]
+pub fn remove(element: impl Into<SyntaxElement>) {
+    match element.into() {
+        NodeOrToken::Node(node) => node.detach(),
+        NodeOrToken::Token(token) => token.detach(),
+    }
+}
+
+/// Remove an element along with the whitespace before it.
+pub fn remove_with_whitespace(element: impl Into<SyntaxElement>) {
+    let element = element.into();
+
+    if let Some(NodeOrToken::Token(ws)) = element.prev_sibling_or_token() {
+        if ws.kind() == SyntaxKind::WHITESPACE {
+            ws.detach();
+        }
+    }
+
+    remove(element);
+}
+
+/// The indentation of the line `token` is on.
+fn line_indent(token: &SyntaxToken) -> String {
+    let mut current = token.prev_token();
+
+    while let Some(token) = current {
+        if let Some((_, indent)) = token.text().rsplit_once('\n') {
+            return match token.kind() {
+                SyntaxKind::WHITESPACE => indent.to_owned(),
+                _ => String::new(),
+            };
+        }
+
+        current = token.prev_token();
+    }
+
+    String::new()
+}
+
+/// A mutable copy of `node` with every line after the first indented.
+fn indented<N: AstNode<Language = Lang>>(node: &N, indent: &str) -> N {
+    let green = make::indent(&node.syntax().green(), indent);
+
+    N::cast(SyntaxNode::new_root(green).clone_for_update()).unwrap()
+}
+
+/// Add a statement on its own line after the last one in `block`.
+pub fn push_statement(block: &Block, statement: Statement) {
+    let syntax = block.syntax();
+    let Some(close) = block.right_curly_token() else {
+        return;
+    };
+
+    let outer = line_indent(&close);
+    let inner = format!("{outer}{INDENT}");
+    let statement = indented(&statement, &inner).syntax().clone();
+    let newline = || ws(&format!("\n{inner}"));
+
+    // Statements go before the tail expression, which ends the block.
+    let anchor: SyntaxElement = match block.expr() {
+        Some(tail) => tail.syntax().clone().into(),
+        None => close.clone().into(),
+    };
+
+    match anchor.prev_sibling_or_token() {
+        Some(NodeOrToken::Token(open)) if open.kind() == SyntaxKind::LEFT_CURLY => {
+            insert(
+                syntax,
+                anchor.index(),
+                vec![newline(), statement.into(), ws(&format!("\n{outer}"))],
+            );
+        }
+        Some(NodeOrToken::Token(prev)) if prev.kind() == SyntaxKind::WHITESPACE => {
+            insert(syntax, prev.index(), vec![newline(), statement.into()]);
+        }
+        _ => insert(
+            syntax,
+            anchor.index(),
+            vec![newline(), statement.into(), newline()],
+        ),
+    }
+}
+
+/// Add a parameter after the last one in `params`.
+pub fn push_param(params: &FuncParams, param: FuncParam) {
+    let Some(close) = params.right_paren_token() else {
+        return;
+    };
+
+    let param = detached(&param);
+
+    if params.func_params().next().is_some() {
+        param.syntax().splice_children(
+            0..0,
+            vec![
+                token(SyntaxKind::COMMA, Punctuation::COMMA.as_str()).into(),
+                ws(" "),
+            ],
+        );
+    }
+
+    insert(
+        params.syntax(),
+        close.index(),
+        vec![param.syntax().clone().into()],
+    );
+}
+
+/// Add an item to the end of `file`, after a blank line.
+pub fn push_item(file: &SourceFile, item: ModuleItem) {
+    let item = detached(&item).syntax().clone();
+
+    match file.items().last() {
+        Some(last) => insert(
+            file.syntax(),
+            last.syntax().index() + 1,
+            vec![ws("\n\n"), item.into()],
+        ),
+        None => insert(file.syntax(), 0, vec![item.into(), ws("\n")]),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use guano_common::rowan::ast::AstNode;
+
+    use crate::{make, nodes::*};
+
+    use super::{detached, push_item, push_param, push_statement, remove_with_whitespace, replace};
+
+    #[test]
+    fn test_edit() {
+        let call = |name| Statement::from(make::expr_statement(make::path(&[name]).into()));
+        let inner = make::block([call("a")], Some(make::path(&["b"]).into()));
+        let body = make::block([], Some(inner.into()));
+        let func = make::func("f", [], None, Some(body));
+
+        let file = make::source_file([ModuleItem::from(Decl::from(func))]);
+        let file = SourceFile::cast(file.syntax().clone_for_update()).unwrap();
+        let func = file.syntax().descendants().find_map(Func::cast).unwrap();
+        let inner = file
+            .syntax()
+            .descendants()
+            .filter_map(Block::cast)
+            .nth(1)
+            .unwrap();
+
+        push_statement(&inner, call("c"));
+        push_param(
+            &func.func_params().unwrap(),
+            make::func_param("x", make::path(&["int"]).into()),
+        );
+        push_param(
+            &func.func_params().unwrap(),
+            make::func_param("y", make::path(&["int"]).into()),
+        );
+        push_item(&file, Decl::from(make::func("g", [], None, None)).into());
+
+        assert_eq!(
+            file.to_string(),
+            "fun f(x: int, y: int) {\n    {\n        a;\n        c;\n        b\n    }\n}\n\nfun g();\n"
+        );
+
+        let b = inner.expr().unwrap();
+        replace(b.syntax().clone(), detached(&make::int(1)).syntax().clone());
+        let a = inner.statements().next().unwrap();
+        remove_with_whitespace(a.syntax().clone());
+
+        assert_eq!(inner.to_string(), "{\n        c;\n        1\n    }");
+
+        let empty = make::block([], None);
+        let empty = Block::cast(empty.syntax().clone_for_update()).unwrap();
+        push_statement(&empty, call("a"));
+        assert_eq!(empty.to_string(), "{\n    a;\n}");
+    }
+}
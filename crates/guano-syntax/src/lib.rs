@@ -9,10 +9,18 @@ use guano_common::rowan::{self, ast::AstNode, GreenNode, GreenToken, NodeOrToken
 
 /// Constants
 pub mod consts;
+/// Editing mutable syntax trees
+pub mod edit;
+/// Constructing syntax trees
+pub mod make;
 /// Node data structures
 pub mod nodes;
+/// Rewriting syntax trees
+pub mod rewrite;
 /// Token data structures
 pub mod tokens;
+/// Visiting syntax trees
+pub mod visit;
 
 pub type SyntaxNode = rowan::SyntaxNode<Lang>;
 pub type SyntaxToken = rowan::SyntaxToken<Lang>;
@@ -111,11 +119,85 @@ pub trait AstNodeExt: AstNode<Language = Lang> {
 
 impl<T: AstNode<Language = Lang>> AstNodeExt for T {}
 
+/// Nodes that can be documented with doc comments.
+///
+/// The parser attaches the outer doc comments before a declaration to its
+/// node, while inner doc comments document the module or file they're in.
+pub trait HasDocs: AstNode<Language = Lang> {
+    /// The text of every doc comment on the node, in order.
+    fn doc_comments(&self) -> Vec<String> {
+        outer_docs(self.syntax()).collect()
+    }
+
+    /// The doc comments on the node joined by newlines, if there are any.
+    fn docs(&self) -> Option<String> {
+        let docs = self.doc_comments();
+
+        (!docs.is_empty()).then(|| docs.join("\n"))
+    }
+}
+
+fn outer_docs(node: &SyntaxNode) -> impl Iterator<Item = String> {
+    node.children_with_tokens()
+        .filter_map(|e| e.into_token())
+        .filter_map(tokens::DocComment::cast)
+        .map(|doc| doc.doc())
+}
+
+fn inner_docs(node: &SyntaxNode) -> impl Iterator<Item = String> {
+    node.children_with_tokens()
+        .filter_map(|e| e.into_token())
+        .filter_map(tokens::InnerDocComment::cast)
+        .map(|doc| doc.doc())
+}
+
+impl HasDocs for nodes::Func {}
+impl HasDocs for nodes::Class {}
+impl HasDocs for nodes::ClassField {}
+impl HasDocs for nodes::Enum {}
+impl HasDocs for nodes::EnumVariant {}
+impl HasDocs for nodes::Proto {}
+impl HasDocs for nodes::Var {}
+
+impl HasDocs for nodes::Module {
+    fn doc_comments(&self) -> Vec<String> {
+        let mut docs = outer_docs(self.syntax()).collect::<Vec<_>>();
+
+        if let Some(body) = self.module_body() {
+            docs.extend(inner_docs(body.syntax()));
+        }
+
+        docs
+    }
+}
+
+impl HasDocs for nodes::SourceFile {
+    fn doc_comments(&self) -> Vec<String> {
+        inner_docs(self.syntax()).collect()
+    }
+}
+
 include!(concat!(env!("OUT_DIR"), "/generated_lib.rs"));
 
 impl SyntaxKind {
     pub fn is_block_expr(&self) -> bool {
         use SyntaxKind::*;
-        matches!(self, BLOCK | IF_EXPR | FOR_EXPR | WHILE_EXPR | LOOP_EXPR)
+        matches!(
+            self,
+            BLOCK | IF_EXPR | FOR_EXPR | WHILE_EXPR | LOOP_EXPR | TRY_EXPR | MATCH_EXPR
+        )
+    }
+
+    /// Whether this is any kind of comment, including doc comments.
+    #[inline]
+    pub fn is_comment(&self) -> bool {
+        use SyntaxKind::*;
+        matches!(self, COMMENT | DOC_COMMENT | INNER_DOC_COMMENT)
+    }
+
+    /// Whether this is whitespace or a comment.
+    #[inline]
+    pub fn is_trivia(&self) -> bool {
+        *self == SyntaxKind::WHITESPACE || self.is_comment()
     }
 }
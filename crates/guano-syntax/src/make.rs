@@ -0,0 +1,383 @@
+use guano_common::rowan::{ast::AstNode, GreenNode, GreenNodeData, NodeOrToken};
+
+use crate::{
+    consts::{Keyword, Punctuation},
+    leaf, node,
+    nodes::*,
+    Child, Lang, SyntaxKind, SyntaxNode,
+};
+
+/// The indentation added for each level of nesting.
+pub const INDENT: &str = "    ";
+
+#[inline]
+fn ws(text: &str) -> Child {
+    leaf(SyntaxKind::WHITESPACE, text)
+}
+
+#[inline]
+fn space() -> Child {
+    ws(" ")
+}
+
+#[inline]
+fn kw(keyword: Keyword) -> Child {
+    leaf(keyword.syntax_kind(), keyword.as_str())
+}
+
+#[inline]
+fn punct(punctuation: Punctuation) -> Child {
+    leaf(punctuation.syntax_kind(), punctuation.as_str())
+}
+
+#[inline]
+fn green<N: AstNode<Language = Lang>>(node: &N) -> Child {
+    NodeOrToken::Node(node.syntax().green().into_owned())
+}
+
+#[inline]
+fn cast<N: AstNode<Language = Lang>>(child: Child) -> N {
+    N::cast(SyntaxNode::new_root(child.into_node().unwrap())).unwrap()
+}
+
+/// Separate a list item from the one before it with `, `.
+fn separated(item: Child, first: bool) -> Child {
+    match (item, first) {
+        (NodeOrToken::Node(node), false) => {
+            let node = node
+                .insert_child(0, punct(Punctuation::COMMA))
+                .insert_child(1, space());
+
+            NodeOrToken::Node(node)
+        }
+        (item, _) => item,
+    }
+}
+
+/// Indent every line after the first by `indent`.
+pub(crate) fn indent(node: &GreenNodeData, indent: &str) -> GreenNode {
+    let newline = format!("\n{indent}");
+    let children = node.children().map(|child| match child {
+        NodeOrToken::Node(node) => NodeOrToken::Node(self::indent(node, indent)),
+        NodeOrToken::Token(token)
+            if token.kind() == SyntaxKind::WHITESPACE.into() && token.text().contains('\n') =>
+        {
+            ws(&token.text().replace('\n', &newline))
+        }
+        NodeOrToken::Token(token) => NodeOrToken::Token(token.to_owned()),
+    });
+
+    GreenNode::new(node.kind(), children.collect::<Vec<_>>())
+}
+
+pub fn name(text: &str) -> Name {
+    cast(node(SyntaxKind::NAME, vec![leaf(SyntaxKind::IDEN, text)]))
+}
+
+/// A path like `a::b`, which is both an [Expr] and a [Type].
+pub fn path(segments: &[&str]) -> Path {
+    let segments = segments
+        .iter()
+        .enumerate()
+        .map(|(i, segment)| {
+            let name = green(&name(segment));
+            let children = match i {
+                0 => vec![name],
+                _ => vec![punct(Punctuation::COLON2), name],
+            };
+
+            node(SyntaxKind::PATH_SEGMENT, children)
+        })
+        .collect();
+
+    cast(node(SyntaxKind::PATH, segments))
+}
+
+#[inline]
+fn literal(kind: SyntaxKind, text: &str) -> Literal {
+    cast(node(SyntaxKind::LITERAL, vec![leaf(kind, text)]))
+}
+
+/// An integer literal, negative values need a [unary] minus.
+pub fn int(value: u64) -> Literal {
+    literal(SyntaxKind::LIT_INTEGER, &value.to_string())
+}
+
+/// A string literal, escaping whatever needs it.
+pub fn string(value: &str) -> Literal {
+    let mut text = String::with_capacity(value.len() + 2);
+    text.push('"');
+
+    for ch in value.chars() {
+        match ch {
+            '"' | '\\' | '{' | '}' => {
+                text.push('\\');
+                text.push(ch);
+            }
+            '\n' => text.push_str("\\n"),
+            '\r' => text.push_str("\\r"),
+            '\t' => text.push_str("\\t"),
+            '\0' => text.push_str("\\0"),
+            ch => text.push(ch),
+        }
+    }
+
+    text.push('"');
+    literal(SyntaxKind::LIT_STRING, &text)
+}
+
+pub fn bool(value: bool) -> Literal {
+    let keyword = if value { Keyword::TRUE } else { Keyword::FALSE };
+
+    literal(keyword.syntax_kind(), keyword.as_str())
+}
+
+pub fn nil() -> Literal {
+    literal(SyntaxKind::KW_NIL, Keyword::NIL.as_str())
+}
+
+/// `lhs op rhs`, where `op` is one of the [BinaryOp] marks.
+pub fn binary(lhs: Expr, op: Punctuation, rhs: Expr) -> BinaryExpr {
+    let op = node(SyntaxKind::BINARY_OP, vec![punct(op)]);
+
+    cast(node(
+        SyntaxKind::BINARY_EXPR,
+        vec![green(&lhs), space(), op, space(), green(&rhs)],
+    ))
+}
+
+/// `op expr`, where `op` is `-` or `!`.
+pub fn unary(op: Punctuation, expr: Expr) -> UnaryExpr {
+    let op = node(SyntaxKind::UNARY_OP, vec![punct(op)]);
+
+    cast(node(SyntaxKind::UNARY_EXPR, vec![op, green(&expr)]))
+}
+
+pub fn group(expr: Expr) -> GroupExpr {
+    cast(node(
+        SyntaxKind::GROUP_EXPR,
+        vec![
+            punct(Punctuation::LEFT_PAREN),
+            green(&expr),
+            punct(Punctuation::RIGHT_PAREN),
+        ],
+    ))
+}
+
+pub fn call(callee: Expr, args: impl IntoIterator<Item = Expr>) -> CallExpr {
+    let mut children = vec![green(&callee), punct(Punctuation::LEFT_PAREN)];
+    children.extend(
+        args.into_iter()
+            .enumerate()
+            .map(|(i, arg)| separated(node(SyntaxKind::LIST_EXPR_ITEM, vec![green(&arg)]), i == 0)),
+    );
+    children.push(punct(Punctuation::RIGHT_PAREN));
+
+    cast(node(SyntaxKind::CALL_EXPR, children))
+}
+
+/// `expr.field`
+pub fn field(expr: Expr, field: &str) -> FieldExpr {
+    cast(node(
+        SyntaxKind::FIELD_EXPR,
+        vec![
+            green(&expr),
+            punct(Punctuation::DOT),
+            leaf(SyntaxKind::IDEN, field),
+        ],
+    ))
+}
+
+pub fn ret(expr: Option<Expr>) -> ReturnExpr {
+    let mut children = vec![kw(Keyword::RETURN)];
+
+    if let Some(expr) = expr {
+        children.extend([space(), green(&expr)]);
+    }
+
+    cast(node(SyntaxKind::RETURN_EXPR, children))
+}
+
+/// `expr;`
+pub fn expr_statement(expr: Expr) -> ExprStatement {
+    cast(node(
+        SyntaxKind::EXPR_STATEMENT,
+        vec![green(&expr), punct(Punctuation::SEMICOLON)],
+    ))
+}
+
+/// A block with a statement per line, followed by `tail`.
+pub fn block(statements: impl IntoIterator<Item = Statement>, tail: Option<Expr>) -> Block {
+    let lines = statements
+        .into_iter()
+        .map(|s| s.syntax().green().into_owned())
+        .chain(tail.map(|e| e.syntax().green().into_owned()))
+        .collect::<Vec<_>>();
+
+    let mut children = vec![punct(Punctuation::LEFT_CURLY)];
+
+    for line in &lines {
+        children.push(ws(&format!("\n{INDENT}")));
+        children.push(NodeOrToken::Node(indent(line, INDENT)));
+    }
+
+    if !lines.is_empty() {
+        children.push(ws("\n"));
+    }
+
+    children.push(punct(Punctuation::RIGHT_CURLY));
+
+    cast(node(SyntaxKind::BLOCK, children))
+}
+
+/// `let name: ty = value;`, or `var` if `mutable`.
+pub fn var(mutable: bool, name: &str, ty: Option<Type>, value: Option<Expr>) -> Var {
+    let kind = if mutable { Keyword::VAR } else { Keyword::LET };
+    let mut children = vec![
+        node(SyntaxKind::VAR_KIND, vec![kw(kind)]),
+        space(),
+        leaf(SyntaxKind::IDEN, name),
+    ];
+
+    if let Some(ty) = ty {
+        children.push(node(
+            SyntaxKind::VAR_TYPE,
+            vec![punct(Punctuation::COLON), space(), green(&ty)],
+        ));
+    }
+
+    if let Some(value) = value {
+        children.push(space());
+        children.push(node(
+            SyntaxKind::VAR_VALUE,
+            vec![punct(Punctuation::EQ), space(), green(&value)],
+        ));
+    }
+
+    children.push(punct(Punctuation::SEMICOLON));
+
+    cast(node(SyntaxKind::VAR, children))
+}
+
+/// `name: ty`
+pub fn func_param(name: &str, ty: Type) -> FuncParam {
+    cast(node(
+        SyntaxKind::FUNC_PARAM,
+        vec![
+            leaf(SyntaxKind::IDEN, name),
+            punct(Punctuation::COLON),
+            space(),
+            green(&ty),
+        ],
+    ))
+}
+
+/// A function, which is only declared if it has no `body`.
+pub fn func(
+    name: &str,
+    params: impl IntoIterator<Item = FuncParam>,
+    ret: Option<Type>,
+    body: Option<Block>,
+) -> Func {
+    let mut params_children = vec![punct(Punctuation::LEFT_PAREN)];
+    params_children.extend(
+        params
+            .into_iter()
+            .enumerate()
+            .map(|(i, param)| separated(green(&param), i == 0)),
+    );
+    params_children.push(punct(Punctuation::RIGHT_PAREN));
+
+    let mut children = vec![
+        kw(Keyword::FUN),
+        space(),
+        leaf(SyntaxKind::IDEN, name),
+        node(SyntaxKind::FUNC_PARAMS, params_children),
+    ];
+
+    if let Some(ret) = ret {
+        children.push(space());
+        children.push(node(
+            SyntaxKind::FUNC_TYPE,
+            vec![punct(Punctuation::THIN_ARROW), space(), green(&ret)],
+        ));
+    }
+
+    match body {
+        Some(body) => children.extend([space(), node(SyntaxKind::FUNC_BODY, vec![green(&body)])]),
+        None => children.push(node(
+            SyntaxKind::FUNC_BODY,
+            vec![punct(Punctuation::SEMICOLON)],
+        )),
+    }
+
+    cast(node(SyntaxKind::FUNC, children))
+}
+
+/// A file with a blank line between each item.
+pub fn source_file(items: impl IntoIterator<Item = ModuleItem>) -> SourceFile {
+    let mut children = vec![];
+
+    for item in items {
+        if !children.is_empty() {
+            children.push(ws("\n\n"));
+        }
+
+        children.push(green(&item));
+    }
+
+    if !children.is_empty() {
+        children.push(ws("\n"));
+    }
+
+    cast(node(SyntaxKind::SOURCE_FILE, children))
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{consts::Punctuation, nodes::*, AstToken};
+
+    use super::{
+        binary, block, bool, call, field, func, func_param, int, nil, path, source_file, string,
+        var,
+    };
+
+    #[test]
+    fn test_make() {
+        let int_ty = || Type::from(path(&["int"]));
+        let sum = binary(path(&["a"]).into(), Punctuation::PLUS, path(&["b"]).into());
+        let body = block(
+            [Statement::from(var(false, "c", None, Some(sum.into())))],
+            Some(path(&["c"]).into()),
+        );
+        let add = func(
+            "add",
+            [func_param("a", int_ty()), func_param("b", int_ty())],
+            Some(int_ty()),
+            Some(body),
+        );
+
+        assert_eq!(add.name().unwrap().text(), "add");
+        assert_eq!(add.func_params().unwrap().func_params().count(), 2);
+        assert_eq!(
+            add.to_string(),
+            "fun add(a: int, b: int) -> int {\n    let c = a + b;\n    c\n}"
+        );
+
+        let outer = block([], Some(block([], None).into()));
+        let nested = block([], Some(outer.into()));
+        assert_eq!(nested.to_string(), "{\n    {\n        {}\n    }\n}");
+
+        let print = call(
+            field(path(&["io", "out"]).into(), "print").into(),
+            [string("{a}\n").into(), int(1).into(), nil().into()],
+        );
+        assert_eq!(print.to_string(), r#"io::out.print("\{a\}\n", 1, nil)"#);
+
+        let file = source_file([
+            ModuleItem::from(Decl::from(func("f", [], None, None))),
+            Decl::from(var(true, "a", Some(int_ty()), Some(bool(true).into()))).into(),
+        ]);
+        assert_eq!(file.to_string(), "fun f();\n\nvar a: int = true;\n");
+    }
+}
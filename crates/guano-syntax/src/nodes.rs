@@ -4,10 +4,278 @@ use crate::tokens::Iden;
 use std::iter::FusedIterator;
 use std::iter::Peekable;
 
-use guano_common::rowan::ast::AstChildren;
+use guano_common::rowan::{
+    ast::{AstChildren, AstNode},
+    NodeOrToken,
+};
 
 use crate::SyntaxKind;
 
+impl SourceFile {
+    #[inline]
+    pub fn items(&self) -> AstChildren<ModuleItem> {
+        self.module_items()
+    }
+}
+
+impl Module {
+    #[inline]
+    pub fn is_pub(&self) -> bool {
+        self.pub_token().is_some()
+    }
+
+    #[inline]
+    pub fn name(&self) -> Option<Iden> {
+        self.iden_token().and_then(Iden::cast)
+    }
+
+    /// Iterate over the items declared in this module's body.
+    #[inline]
+    pub fn items(&self) -> impl Iterator<Item = ModuleItem> {
+        self.module_body()
+            .into_iter()
+            .flat_map(|body| body.module_items())
+    }
+}
+
+impl Path {
+    /// Iterate over the names of each segment.
+    #[inline]
+    pub fn names(&self) -> impl Iterator<Item = Name> {
+        self.path_segments().filter_map(|s| s.name())
+    }
+}
+
+impl GenericParam {
+    #[inline]
+    pub fn name(&self) -> Option<Iden> {
+        self.iden_token().and_then(Iden::cast)
+    }
+
+    /// Iterate over the protos this parameter is bound by.
+    pub fn bounds(&self) -> impl Iterator<Item = Path> {
+        self.generic_bounds()
+            .into_iter()
+            .flat_map(|b| b.proto_extensions())
+            .filter_map(|e| e.path())
+    }
+}
+
+impl GenericArgs {
+    /// Iterate over the argument types.
+    pub fn types(&self) -> impl Iterator<Item = Type> {
+        self.generic_args().filter_map(|a| a.ty())
+    }
+}
+
+impl Class {
+    #[inline]
+    pub fn is_pub(&self) -> bool {
+        self.pub_token().is_some()
+    }
+
+    #[inline]
+    pub fn name(&self) -> Option<Iden> {
+        self.iden_token().and_then(Iden::cast)
+    }
+
+    /// Iterate over the generic parameters, if there are any.
+    pub fn generics(&self) -> impl Iterator<Item = GenericParam> {
+        self.generic_params()
+            .into_iter()
+            .flat_map(|g| g.generic_params())
+    }
+
+    /// The class this one extends.
+    #[inline]
+    pub fn extends(&self) -> Option<Path> {
+        self.class_extends().and_then(|e| e.path())
+    }
+
+    /// Iterate over the fields, which `class A;` doesn't have.
+    pub fn fields(&self) -> impl Iterator<Item = ClassField> {
+        self.class_body()
+            .and_then(|b| b.class_block())
+            .into_iter()
+            .flat_map(|b| b.class_fields())
+    }
+}
+
+impl ClassField {
+    #[inline]
+    pub fn is_pub(&self) -> bool {
+        self.pub_token().is_some()
+    }
+
+    #[inline]
+    pub fn name(&self) -> Option<Iden> {
+        self.iden_token().and_then(Iden::cast)
+    }
+}
+
+impl Proto {
+    #[inline]
+    pub fn is_pub(&self) -> bool {
+        self.pub_token().is_some()
+    }
+
+    #[inline]
+    pub fn name(&self) -> Option<Iden> {
+        self.iden_token().and_then(Iden::cast)
+    }
+
+    /// Iterate over the generic parameters, if there are any.
+    pub fn generics(&self) -> impl Iterator<Item = GenericParam> {
+        self.generic_params()
+            .into_iter()
+            .flat_map(|g| g.generic_params())
+    }
+
+    /// Iterate over the protos this one extends.
+    pub fn extends(&self) -> impl Iterator<Item = Path> {
+        self.proto_extends()
+            .into_iter()
+            .flat_map(|e| e.proto_extensions())
+            .filter_map(|e| e.path())
+    }
+
+    /// Iterate over the functions implementers need to provide.
+    pub fn funcs(&self) -> impl Iterator<Item = Func> {
+        self.proto_body().into_iter().flat_map(|b| b.funcs())
+    }
+}
+
+impl Impl {
+    /// The proto being implemented, `None` for `impl Type { ... }`.
+    #[inline]
+    pub fn proto(&self) -> Option<Path> {
+        self.impl_proto().and_then(|p| p.path())
+    }
+
+    pub fn funcs(&self) -> impl Iterator<Item = Func> {
+        self.impl_body().into_iter().flat_map(|b| b.funcs())
+    }
+}
+
+impl Enum {
+    #[inline]
+    pub fn is_pub(&self) -> bool {
+        self.pub_token().is_some()
+    }
+
+    #[inline]
+    pub fn name(&self) -> Option<Iden> {
+        self.iden_token().and_then(Iden::cast)
+    }
+
+    /// Iterate over the generic parameters, if there are any.
+    pub fn generics(&self) -> impl Iterator<Item = GenericParam> {
+        self.generic_params()
+            .into_iter()
+            .flat_map(|g| g.generic_params())
+    }
+
+    pub fn variants(&self) -> impl Iterator<Item = EnumVariant> {
+        self.enum_body().into_iter().flat_map(|b| b.enum_variants())
+    }
+}
+
+impl EnumVariant {
+    #[inline]
+    pub fn name(&self) -> Option<Iden> {
+        self.iden_token().and_then(Iden::cast)
+    }
+
+    /// Iterate over the payload fields, which is empty for variants like `Empty`.
+    pub fn fields(&self) -> impl Iterator<Item = EnumVariantField> {
+        self.enum_variant_fields()
+            .into_iter()
+            .flat_map(|f| f.enum_variant_fields())
+    }
+
+    /// Whether the variant has no payload.
+    #[inline]
+    pub fn is_unit(&self) -> bool {
+        self.enum_variant_fields().is_none()
+    }
+}
+
+impl EnumVariantField {
+    #[inline]
+    pub fn name(&self) -> Option<Iden> {
+        self.iden_token().and_then(Iden::cast)
+    }
+}
+
+impl Import {
+    #[inline]
+    pub fn from_path(&self) -> Option<Path> {
+        self.import_from().and_then(|f| f.path())
+    }
+
+    /// The items listed in the import, whether grouped or not.
+    pub fn items(&self) -> Vec<ImportItem> {
+        match self.import_body() {
+            Some(ImportBody::ImportItem(item)) => vec![item],
+            Some(ImportBody::ImportItems(items)) => items.import_items().collect(),
+            None => vec![],
+        }
+    }
+
+    /// Flatten the import into one entry per imported item,
+    /// with the `from` path prepended to each item's path.
+    pub fn entries(&self) -> Vec<ImportEntry> {
+        let prefix = self
+            .from_path()
+            .map(|p| p.names().collect::<Vec<_>>())
+            .unwrap_or_default();
+
+        self.items()
+            .into_iter()
+            .map(|item| {
+                let mut path = prefix.clone();
+                path.extend(item.path().into_iter().flat_map(|p| p.names()));
+
+                ImportEntry {
+                    path,
+                    alias: item.alias(),
+                    is_glob: item.is_glob(),
+                }
+            })
+            .collect()
+    }
+}
+
+impl ImportItem {
+    #[inline]
+    pub fn path(&self) -> Option<Path> {
+        self.import_path().and_then(|p| p.path())
+    }
+
+    #[inline]
+    pub fn alias(&self) -> Option<Iden> {
+        self.import_alias()
+            .and_then(|a| a.iden_token())
+            .and_then(Iden::cast)
+    }
+
+    #[inline]
+    pub fn is_glob(&self) -> bool {
+        self.import_path().is_some_and(|p| p.star_token().is_some())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+/// A single item brought into scope by an [Import].
+pub struct ImportEntry {
+    /// Full path of the item, including the `from` path.
+    /// Empty for a bare `import *;`.
+    pub path: Vec<Name>,
+    pub alias: Option<Iden>,
+    /// Whether every item under `path` is imported.
+    pub is_glob: bool,
+}
+
 impl Literal {
     pub fn is_float(&self) -> bool {
         self.0.kind() == SyntaxKind::LIT_FLOAT
@@ -79,6 +347,27 @@ impl Literal {
     }
 }
 
+impl InterpolatedString {
+    /// Iterate over the literal fragments and interpolations in source order.
+    pub fn parts(&self) -> impl Iterator<Item = StringPart> {
+        self.0
+            .children_with_tokens()
+            .filter_map(|child| match child {
+                NodeOrToken::Node(node) => Interpolation::cast(node).map(StringPart::Interpolation),
+                NodeOrToken::Token(token) => {
+                    tokens::StringFragment::cast(token).map(StringPart::Fragment)
+                }
+            })
+    }
+}
+
+/// A part of an [`InterpolatedString`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum StringPart {
+    Fragment(tokens::StringFragment),
+    Interpolation(Interpolation),
+}
+
 impl BinaryExpr {
     #[inline]
     pub fn lhs(&self) -> Option<Expr> {
@@ -91,6 +380,29 @@ impl BinaryExpr {
     }
 }
 
+impl RangeExpr {
+    #[inline]
+    pub fn start(&self) -> Option<Expr> {
+        self.exprs().next()
+    }
+
+    #[inline]
+    pub fn end(&self) -> Option<Expr> {
+        self.exprs().nth(1)
+    }
+
+    /// Whether the range includes its end, as in `a..=b`.
+    #[inline]
+    pub fn inclusive(&self) -> bool {
+        self.range_op().and_then(|op| op.dot2_eq_token()).is_some()
+    }
+
+    #[inline]
+    pub fn step(&self) -> Option<Expr> {
+        self.range_step().and_then(|s| s.expr())
+    }
+}
+
 impl IndexExpr {
     #[inline]
     pub fn expr(&self) -> Option<Expr> {
@@ -123,19 +435,16 @@ impl Func {
         self.iden_token().and_then(|i| Iden::cast(i))
     }
 
+    /// Iterate over the generic parameters, if there are any.
+    pub fn generics(&self) -> impl Iterator<Item = GenericParam> {
+        self.generic_params()
+            .into_iter()
+            .flat_map(|g| g.generic_params())
+    }
+
     // Return an iterator over all valid parameters
     pub fn params(&self) -> Option<impl Iterator<Item = (Iden, Type)>> {
-        self.func_params().map(|p| {
-            p.func_params().filter_map(|p| {
-                let name = p.iden_token().and_then(|i| Iden::cast(i));
-
-                if let (Some(name), Some(ty)) = (name, p.ty()) {
-                    Some((name, ty))
-                } else {
-                    None
-                }
-            })
-        })
+        self.func_params().map(|p| p.params())
     }
 
     #[inline]
@@ -149,6 +458,102 @@ impl Func {
     }
 }
 
+impl TryExpr {
+    #[inline]
+    pub fn catches(&self) -> AstChildren<CatchClause> {
+        self.catch_clauses()
+    }
+}
+
+impl CatchClause {
+    #[inline]
+    pub fn name(&self) -> Option<Iden> {
+        self.iden_token().and_then(Iden::cast)
+    }
+
+    #[inline]
+    pub fn ty(&self) -> Option<Type> {
+        self.catch_type().and_then(|t| t.ty())
+    }
+}
+
+impl MatchExpr {
+    #[inline]
+    pub fn arms(&self) -> impl Iterator<Item = MatchArm> {
+        self.match_arms().into_iter().flat_map(|a| a.match_arms())
+    }
+}
+
+impl LiteralPattern {
+    #[inline]
+    pub fn is_negative(&self) -> bool {
+        self.minus_token().is_some()
+    }
+}
+
+impl BindingPattern {
+    #[inline]
+    pub fn name(&self) -> Option<Iden> {
+        self.iden_token().and_then(Iden::cast)
+    }
+}
+
+impl TypePattern {
+    #[inline]
+    pub fn name(&self) -> Option<Iden> {
+        self.iden_token().and_then(Iden::cast)
+    }
+}
+
+impl ListPattern {
+    #[inline]
+    pub fn patterns(&self) -> impl Iterator<Item = Pattern> {
+        self.list_pattern_items().filter_map(|i| i.pattern())
+    }
+}
+
+impl FuncParams {
+    // Return an iterator over all valid parameters
+    pub fn params(&self) -> impl Iterator<Item = (Iden, Type)> {
+        self.func_params().filter_map(|p| {
+            let name = p.iden_token().and_then(Iden::cast);
+
+            if let (Some(name), Some(ty)) = (name, p.ty()) {
+                Some((name, ty))
+            } else {
+                None
+            }
+        })
+    }
+}
+
+impl LambdaExpr {
+    // Return an iterator over all valid parameters
+    pub fn params(&self) -> Option<impl Iterator<Item = (Iden, Type)>> {
+        self.func_params().map(|p| p.params())
+    }
+
+    #[inline]
+    pub fn ty(&self) -> Option<Type> {
+        self.func_type().and_then(|t| t.ty())
+    }
+}
+
+impl FunctionType {
+    /// Iterate over the parameter types.
+    pub fn params(&self) -> impl Iterator<Item = Type> {
+        self.function_type_params()
+            .into_iter()
+            .flat_map(|p| p.function_type_params())
+            .filter_map(|p| p.ty())
+    }
+
+    #[inline]
+    pub fn ty(&self) -> Option<Type> {
+        self.func_type().and_then(|t| t.ty())
+    }
+}
+
 impl VarKind {
     #[inline]
     pub fn is_let(&self) -> bool {
@@ -0,0 +1,98 @@
+include!(concat!(env!("OUT_DIR"), "/generated_rewrite.rs"));
+
+use guano_common::rowan::ast::AstNode;
+
+use crate::{nodes::*, SyntaxNode};
+
+/// Rewrite `child` as an `N`, returning the node it was cast
+/// to along with its replacement.
+fn rewrite_child<N: AstNode<Language = crate::Lang>>(
+    child: &SyntaxNode,
+    rewrite: impl FnOnce(N) -> N,
+) -> Option<(SyntaxNode, SyntaxNode)> {
+    let node = N::cast(child.clone())?;
+    let original = node.syntax().clone();
+
+    Some((original, rewrite(node).syntax().clone()))
+}
+
+/// Build a copy of `node` with its children replaced, reusing
+/// `node` itself if none of them changed.
+fn rebuild<N: AstNode<Language = crate::Lang>>(
+    node: N,
+    mut rewrite: impl FnMut(&SyntaxNode) -> Option<(SyntaxNode, SyntaxNode)>,
+) -> N {
+    let mut green = None;
+
+    for child in node.syntax().children() {
+        let Some((original, new)) = rewrite(&child) else {
+            continue;
+        };
+
+        if original == new {
+            continue;
+        }
+
+        // Enums cast through wrapper nodes like `EXPR`, which are kept.
+        let mut replacement = new.green().into_owned();
+        let mut current = original;
+
+        while current != child {
+            let parent = current.parent().unwrap();
+            replacement = parent
+                .green()
+                .replace_child(current.index(), replacement.into());
+            current = parent;
+        }
+
+        let base = green.unwrap_or_else(|| node.syntax().green().into_owned());
+        green = Some(base.replace_child(child.index(), replacement.into()));
+    }
+
+    match green {
+        Some(green) => N::cast(SyntaxNode::new_root(green)).unwrap(),
+        None => node,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use guano_common::rowan::ast::AstNode;
+
+    use crate::{leaf, nodes::*, visit::test::sum, SyntaxKind, SyntaxNode};
+
+    use super::{walk_expr, Rewriter};
+
+    /// Renames `a` to `x` and removes parentheses.
+    struct Fold;
+
+    impl Rewriter for Fold {
+        fn rewrite_name(&mut self, node: Name) -> Name {
+            if node.to_string() != "a" {
+                return node;
+            }
+
+            let green = crate::node(SyntaxKind::NAME, vec![leaf(SyntaxKind::IDEN, "x")]);
+            Name::cast(SyntaxNode::new_root(green.into_node().unwrap())).unwrap()
+        }
+
+        fn rewrite_expr(&mut self, node: Expr) -> Expr {
+            match walk_expr(self, node) {
+                Expr::GroupExpr(group) => group.expr().unwrap(),
+                node => node,
+            }
+        }
+    }
+
+    #[test]
+    fn test_rewriter() {
+        let expr = sum();
+        let rewritten = Fold.rewrite_binary_expr(expr.clone());
+        assert_eq!(rewritten.to_string(), "x + 1 + b");
+        assert_eq!(expr.to_string(), "a + (1 + b)");
+
+        // Nothing left to change, so the tree is left alone.
+        let again = Fold.rewrite_binary_expr(rewritten.clone());
+        assert_eq!(again.syntax(), rewritten.syntax());
+    }
+}
@@ -1,5 +1,9 @@
 use crate::AstToken;
 
+pub mod literal;
+
+pub use literal::{LiteralError, LiteralErrorKind};
+
 include!(concat!(env!("OUT_DIR"), "/generated_tokens.rs"));
 
 impl Iden {
@@ -14,3 +18,85 @@ impl Iden {
         self.text()
     }
 }
+
+impl DocComment {
+    /// The text of the comment, without the comment markers.
+    #[inline]
+    pub fn doc(&self) -> std::string::String {
+        doc_text(self.text())
+    }
+}
+
+impl InnerDocComment {
+    /// The text of the comment, without the comment markers.
+    #[inline]
+    pub fn doc(&self) -> std::string::String {
+        doc_text(self.text())
+    }
+}
+
+/// Strip the markers from a doc comment, along with the indentation
+/// and leading `*`s of the lines in a block comment.
+fn doc_text(text: &str) -> std::string::String {
+    if let Some(line) = text.get(3..).filter(|_| text.starts_with("//")) {
+        let line = line.trim_end_matches(['\n', '\r']);
+
+        return line.strip_prefix(' ').unwrap_or(line).to_string();
+    }
+
+    let body = text.get(3..).unwrap_or("");
+    let body = body.strip_suffix("*/").unwrap_or(body);
+
+    let mut lines = body.lines().collect::<Vec<_>>();
+    while lines.first().is_some_and(|l| l.trim().is_empty()) {
+        lines.remove(0);
+    }
+    while lines.last().is_some_and(|l| l.trim().is_empty()) {
+        lines.pop();
+    }
+
+    let starred = lines
+        .iter()
+        .all(|l| l.trim().is_empty() || l.trim_start().starts_with('*'));
+    let lines = lines.into_iter().map(|l| match starred {
+        true => {
+            let l = l.trim_start().strip_prefix('*').unwrap_or("");
+            l.strip_prefix(' ').unwrap_or(l)
+        }
+        false => l,
+    });
+    let lines = lines.collect::<Vec<_>>();
+
+    let indent = lines
+        .iter()
+        .filter(|l| !l.trim().is_empty())
+        .map(|l| l.len() - l.trim_start().len())
+        .min()
+        .unwrap_or(0);
+
+    lines
+        .iter()
+        .map(|l| l.get(indent..).unwrap_or("").trim_end())
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+#[cfg(test)]
+mod test {
+    use super::doc_text;
+
+    #[test]
+    fn test_doc_text() {
+        assert_eq!(doc_text("/// Adds two numbers.\n"), "Adds two numbers.");
+        assert_eq!(doc_text("//!indented\r\n"), "indented");
+        assert_eq!(doc_text("/** One line. */"), "One line.");
+        assert_eq!(
+            doc_text("/*!\n    Reeeeee\n      nested\n*/"),
+            "Reeeeee\n  nested"
+        );
+        assert_eq!(
+            doc_text("/**\n * Starred\n *\n * lines\n */"),
+            "Starred\n\nlines"
+        );
+    }
+}
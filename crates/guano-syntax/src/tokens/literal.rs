@@ -0,0 +1,271 @@
+use std::fmt;
+
+use guano_common::{
+    num::rug::Integer,
+    once_cell::sync::Lazy,
+    regex::Regex,
+    rowan::{TextRange, TextSize},
+};
+
+use crate::AstToken;
+
+pub const REPLACEMENT_STRING: &'static str = "\u{FFFD}";
+
+pub mod regex {
+    use guano_common::konst::format::formatcp;
+
+    pub const BYTE_ESCAPE: &'static str = r"(?:\\x[[:xdigit:]]{2})";
+    pub const LITTLE_UNICODE_ESCAPE: &'static str = r"(?:\\u[[:xdigit:]]{4})";
+    pub const BIG_UNICODE_ESCAPE: &'static str = r"(?:\\U[[:xdigit:]]{6})";
+    pub const SINGLE_ESCAPE: &'static str = r#"(?:\\(\\|'|"|n|t|r|0|\{|\}))"#;
+    pub const ESCAPED: &'static str = formatcp!(
+        "(?:{}|{}|{}|{})",
+        BYTE_ESCAPE,
+        LITTLE_UNICODE_ESCAPE,
+        BIG_UNICODE_ESCAPE,
+        SINGLE_ESCAPE
+    );
+    pub const CHAR_UNESCAPED: &'static str = r"(?:[^'\n\r\\])";
+    pub const STRING_UNESCAPED: &'static str = r#"(?:[^"\n\r\\])"#;
+
+    pub const CHAR_ITEM: &'static str = formatcp!("^(?:{}|{})", CHAR_UNESCAPED, ESCAPED);
+    pub const STRING_ITEM: &'static str = formatcp!("^(?:{}|{})", STRING_UNESCAPED, ESCAPED);
+
+    pub const CHAR_LAZY: &'static str = r"^'(?s:\\.|[^'\\])*'";
+    /// A string without any interpolations, where `{` must be escaped.
+    pub const STRING_LAZY: &'static str = r#"^"(?s:\\.|[^"\\{])*""#;
+    pub const STRING_FRAGMENT_LAZY: &'static str = r#"^(?s:\\.|[^"\\{])+"#;
+}
+
+static CHAR_ITEM: Lazy<Regex> = Lazy::new(|| Regex::new(regex::CHAR_ITEM).unwrap());
+static STRING_ITEM: Lazy<Regex> = Lazy::new(|| Regex::new(regex::STRING_ITEM).unwrap());
+
+/// An error found while decoding the value of a literal token.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LiteralError {
+    pub span: TextRange,
+    pub kind: LiteralErrorKind,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum LiteralErrorKind {
+    /// An unknown escape sequence, such as `\q`.
+    InvalidEscape,
+    /// A byte escape outside of the ASCII range, such as `\xFF`.
+    InvalidByteEscape(u32),
+    /// A unicode escape that isn't a valid character, such as `\uD800`.
+    InvalidCodePoint(u32),
+    /// A character that must be escaped, such as a newline.
+    UnescapedCharacter(char),
+    EmptyChar,
+    MultipleChars,
+    InvalidNumber,
+}
+
+impl fmt::Display for LiteralErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            LiteralErrorKind::InvalidEscape => write!(f, "Invalid escape sequence"),
+            LiteralErrorKind::InvalidByteEscape(b) => {
+                write!(f, "Byte escape {b:#04x} is out of the ASCII range")
+            }
+            LiteralErrorKind::InvalidCodePoint(c) => {
+                write!(f, "Unicode escape {c:#x} is not a valid character")
+            }
+            LiteralErrorKind::UnescapedCharacter(c) => {
+                write!(f, "Character {c:?} must be escaped")
+            }
+            LiteralErrorKind::EmptyChar => write!(f, "Empty character literal"),
+            LiteralErrorKind::MultipleChars => {
+                write!(f, "Character literal contains more than one character")
+            }
+            LiteralErrorKind::InvalidNumber => write!(f, "Invalid number literal"),
+        }
+    }
+}
+
+impl fmt::Display for LiteralError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:?}: {}", self.span, self.kind)
+    }
+}
+
+impl std::error::Error for LiteralError {}
+
+/// Unescape `text`, which starts at `offset` in the source,
+/// replacing invalid items with [`REPLACEMENT_STRING`].
+fn unescape(text: &str, offset: TextSize, item: &Regex) -> (String, Vec<LiteralError>) {
+    let mut value = String::with_capacity(text.len());
+    let mut errors = vec![];
+    let mut position = 0;
+
+    while position < text.len() {
+        let remaining = &text[position..];
+
+        let (len, result) = match item.find(remaining) {
+            Some(m) => (m.end(), unescape_item(m.as_str())),
+            None => {
+                // Either an unknown escape or a character that must be escaped.
+                let mut chars = remaining.chars();
+                let first = chars.next().unwrap();
+
+                match first {
+                    '\\' => {
+                        let len = first.len_utf8() + chars.next().map_or(0, char::len_utf8);
+
+                        (len, Err(LiteralErrorKind::InvalidEscape))
+                    }
+                    c => (c.len_utf8(), Err(LiteralErrorKind::UnescapedCharacter(c))),
+                }
+            }
+        };
+
+        match result {
+            Ok(c) => value.push(c),
+            Err(kind) => {
+                let start = offset + TextSize::from(position as u32);
+                let span = TextRange::at(start, TextSize::from(len as u32));
+
+                value.push_str(REPLACEMENT_STRING);
+                errors.push(LiteralError { span, kind });
+            }
+        }
+
+        position += len;
+    }
+
+    (value, errors)
+}
+
+/// Unescape a single item matched by [`regex::STRING_ITEM`] or [`regex::CHAR_ITEM`].
+fn unescape_item(item: &str) -> Result<char, LiteralErrorKind> {
+    let Some(escape) = item.strip_prefix('\\') else {
+        return Ok(item.chars().next().unwrap());
+    };
+
+    let (kind, digits) = escape.split_at(1);
+
+    match kind {
+        "x" => {
+            let byte = u32::from_str_radix(digits, 16).unwrap();
+
+            if byte > 0x7F {
+                return Err(LiteralErrorKind::InvalidByteEscape(byte));
+            }
+
+            Ok(char::from_u32(byte).unwrap())
+        }
+        "u" | "U" => {
+            let code = u32::from_str_radix(digits, 16).unwrap();
+
+            char::from_u32(code).ok_or(LiteralErrorKind::InvalidCodePoint(code))
+        }
+        "n" => Ok('\n'),
+        "t" => Ok('\t'),
+        "r" => Ok('\r'),
+        "0" => Ok('\0'),
+        other => Ok(other.chars().next().unwrap()),
+    }
+}
+
+/// The text between the quotes of a string or char token, along with its offset.
+fn unquote<T: AstToken>(token: &T) -> (&str, TextSize) {
+    let text = token.text();
+    let start = token.syntax().text_range().start();
+
+    // The parser makes sure that both quotes exist,
+    // but hand-built tokens might not have them.
+    let inner = text.get(1..text.len().saturating_sub(1)).unwrap_or("");
+
+    (inner, start + TextSize::from(1))
+}
+
+fn number_error<T: AstToken>(token: &T) -> LiteralError {
+    LiteralError {
+        span: token.syntax().text_range(),
+        kind: LiteralErrorKind::InvalidNumber,
+    }
+}
+
+impl super::String {
+    /// Decode the string, replacing invalid escapes with [`REPLACEMENT_STRING`].
+    pub fn value_lossy(&self) -> (String, Vec<LiteralError>) {
+        let (text, offset) = unquote(self);
+
+        unescape(text, offset, &STRING_ITEM)
+    }
+
+    pub fn value(&self) -> Result<String, Vec<LiteralError>> {
+        match self.value_lossy() {
+            (value, errors) if errors.is_empty() => Ok(value),
+            (_, errors) => Err(errors),
+        }
+    }
+}
+
+impl super::StringFragment {
+    /// Decode the fragment, replacing invalid escapes with [`REPLACEMENT_STRING`].
+    pub fn value_lossy(&self) -> (String, Vec<LiteralError>) {
+        let start = self.syntax().text_range().start();
+
+        unescape(self.text(), start, &STRING_ITEM)
+    }
+
+    pub fn value(&self) -> Result<String, Vec<LiteralError>> {
+        match self.value_lossy() {
+            (value, errors) if errors.is_empty() => Ok(value),
+            (_, errors) => Err(errors),
+        }
+    }
+}
+
+impl super::Char {
+    pub fn value(&self) -> Result<char, Vec<LiteralError>> {
+        let (text, offset) = unquote(self);
+        let (value, mut errors) = unescape(text, offset, &CHAR_ITEM);
+
+        let mut chars = value.chars();
+        let kind = match (chars.next(), chars.next()) {
+            (Some(c), None) if errors.is_empty() => return Ok(c),
+            (Some(_), None) => return Err(errors),
+            (None, _) => LiteralErrorKind::EmptyChar,
+            (Some(_), Some(_)) => LiteralErrorKind::MultipleChars,
+        };
+
+        errors.push(LiteralError {
+            span: self.syntax().text_range(),
+            kind,
+        });
+
+        Err(errors)
+    }
+}
+
+impl super::Integer {
+    /// Parse a binary, hexadecimal or decimal integer, ignoring any `_` separators.
+    pub fn value(&self) -> Result<Integer, LiteralError> {
+        let text = self.text();
+        let (digits, radix) = match text.get(..2) {
+            Some("0b" | "0B") => (&text[2..], 2),
+            Some("0x" | "0X") => (&text[2..], 16),
+            _ => (text, 10),
+        };
+        let digits = digits.replace('_', "");
+
+        if digits.is_empty() {
+            return Err(number_error(self));
+        }
+
+        Integer::from_str_radix(&digits, radix).map_err(|_| number_error(self))
+    }
+}
+
+impl super::Float {
+    /// Parse a float, ignoring any `_` separators.
+    pub fn value(&self) -> Result<f64, LiteralError> {
+        self.text()
+            .replace('_', "")
+            .parse()
+            .map_err(|_| number_error(self))
+    }
+}
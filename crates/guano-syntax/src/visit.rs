@@ -0,0 +1,84 @@
+include!(concat!(env!("OUT_DIR"), "/generated_visit.rs"));
+
+use guano_common::rowan::ast::AstNode;
+
+use crate::nodes::*;
+
+#[cfg(test)]
+pub(crate) mod test {
+    use guano_common::rowan::ast::AstNode;
+
+    use crate::{leaf, node, nodes::*, SyntaxKind, SyntaxNode};
+
+    use super::{walk_expr, Visitor};
+
+    /// `a + (1 + b)`
+    pub(crate) fn sum() -> BinaryExpr {
+        let path = |name| {
+            node(
+                SyntaxKind::PATH,
+                vec![node(
+                    SyntaxKind::PATH_SEGMENT,
+                    vec![node(SyntaxKind::NAME, vec![leaf(SyntaxKind::IDEN, name)])],
+                )],
+            )
+        };
+        let plus = || node(SyntaxKind::BINARY_OP, vec![leaf(SyntaxKind::PLUS, "+")]);
+        let ws = || leaf(SyntaxKind::WHITESPACE, " ");
+
+        let inner = node(
+            SyntaxKind::BINARY_EXPR,
+            vec![
+                node(
+                    SyntaxKind::LITERAL,
+                    vec![leaf(SyntaxKind::LIT_INTEGER, "1")],
+                ),
+                ws(),
+                plus(),
+                ws(),
+                path("b"),
+            ],
+        );
+        let group = node(
+            SyntaxKind::GROUP_EXPR,
+            vec![
+                leaf(SyntaxKind::LEFT_PAREN, "("),
+                inner,
+                leaf(SyntaxKind::RIGHT_PAREN, ")"),
+            ],
+        );
+        let outer = node(
+            SyntaxKind::BINARY_EXPR,
+            vec![path("a"), ws(), plus(), ws(), group],
+        );
+
+        BinaryExpr::cast(SyntaxNode::new_root(outer.into_node().unwrap())).unwrap()
+    }
+
+    #[derive(Default)]
+    struct Collect {
+        names: Vec<String>,
+        exprs: usize,
+    }
+
+    impl Visitor for Collect {
+        fn visit_name(&mut self, node: &Name) {
+            self.names.push(node.to_string());
+        }
+
+        fn visit_expr(&mut self, node: &Expr) {
+            self.exprs += 1;
+            walk_expr(self, node);
+        }
+    }
+
+    #[test]
+    fn test_visitor() {
+        let mut collect = Collect::default();
+        collect.visit_binary_expr(&sum());
+
+        assert_eq!(collect.names, ["a", "b"]);
+        // `a`, the group, `1 + b`, `1` and `b`.
+        assert_eq!(collect.exprs, 5);
+    }
+}